@@ -0,0 +1,90 @@
+//! Optional config file with defaults for global flags (`$SCOUT_CONFIG` or `~/.config/scout/config.toml`).
+
+use crate::output::OutputFormat;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Defaults read from the config file. Every field is optional; command-line flags override them.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Output format (`plain`, `json`, `ndjson`, `markdown`).
+    pub output: Option<String>,
+    /// [TUI] Show timestamps in UTC.
+    pub utc: Option<bool>,
+    /// [TUI] Auto-refresh interval in seconds.
+    pub refresh: Option<u64>,
+    /// HTTP request timeout in seconds.
+    pub timeout: Option<u64>,
+    /// [TUI] App to open on start (id or name).
+    pub app: Option<String>,
+}
+
+impl Config {
+    /// Output format from the file, validated like `-o`.
+    pub fn output_format(&self) -> Result<Option<OutputFormat>, String> {
+        self.output
+            .as_deref()
+            .map(|s| s.parse::<OutputFormat>())
+            .transpose()
+    }
+}
+
+/// Config file location: `$SCOUT_CONFIG`, else `$XDG_CONFIG_HOME/scout/config.toml`, else `~/.config/scout/config.toml`.
+pub fn config_path() -> Option<PathBuf> {
+    if let Some(p) = std::env::var_os("SCOUT_CONFIG").filter(|p| !p.is_empty()) {
+        return Some(PathBuf::from(p));
+    }
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(base.join("scout").join("config.toml"))
+}
+
+/// Load the config file. A missing file yields the empty config; unreadable or invalid files are errors.
+pub fn load() -> Result<Config, String> {
+    let Some(path) = config_path() else {
+        return Ok(Config::default());
+    };
+    let text = match std::fs::read_to_string(&path) {
+        Ok(t) => t,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+        Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+    parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn parse(text: &str) -> Result<Config, String> {
+    let config: Config = toml::from_str(text).map_err(|e| e.to_string())?;
+    config.output_format()?;
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_full_config() {
+        let c =
+            parse("output = \"json\"\nutc = true\nrefresh = 30\ntimeout = 5\napp = \"My App\"\n")
+                .unwrap();
+        assert_eq!(c.output_format().unwrap(), Some(OutputFormat::Json));
+        assert_eq!(c.utc, Some(true));
+        assert_eq!(c.refresh, Some(30));
+        assert_eq!(c.timeout, Some(5));
+        assert_eq!(c.app.as_deref(), Some("My App"));
+    }
+
+    #[test]
+    fn parse_empty_config() {
+        assert_eq!(parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn parse_rejects_unknown_keys_and_formats() {
+        assert!(parse("outptu = \"json\"").is_err());
+        assert!(parse("output = \"xml\"").is_err());
+    }
+}
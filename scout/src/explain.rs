@@ -0,0 +1,239 @@
+//! Output shapes for `scout explain`: the documented fields of each command's result, hand-maintained.
+
+use serde_json::Value;
+
+/// `(field, type, note)` of one output field.
+type Field = (&'static str, &'static str, &'static str);
+
+const APP: &[Field] = &[
+    ("id", "number", "the <app_id> of other commands"),
+    ("name", "string", ""),
+    (
+        "last_reported_at",
+        "string",
+        "ISO 8601; used by apps --active-since",
+    ),
+    ("link", "string", "API path of the app"),
+];
+
+const SERIES: &[Field] = &[(
+    "<metric_type>",
+    "array",
+    "points as [time: string (ISO 8601), value: number]",
+)];
+
+const SUMMARY: &[Field] = &[
+    ("count", "number", "points in the series"),
+    ("min", "number", ""),
+    ("max", "number", ""),
+    ("mean", "number", ""),
+    ("last", "number", "value of the newest point"),
+    ("p50", "number", ""),
+    ("p95", "number", ""),
+    ("p99", "number", ""),
+];
+
+const DELTA: &[Field] = &[
+    (
+        "stat",
+        "string",
+        "count, min, max, mean, last, p50, p95 or p99",
+    ),
+    ("current", "number", ""),
+    ("previous", "number", "the preceding window of equal length"),
+    ("delta", "number", "current - previous"),
+    ("delta_pct", "number | null", "null when previous is 0"),
+];
+
+const ENDPOINT: &[Field] = &[
+    (
+        "name",
+        "string",
+        "transaction name, e.g. Controller/UsersController#show",
+    ),
+    ("link", "string", "API path ending in the endpoint id"),
+    ("response_time", "number", "mean, ms"),
+    ("throughput", "number", "requests per minute"),
+    (
+        "last_seen",
+        "string",
+        "ISO 8601 when present; used by --active-since",
+    ),
+];
+
+const TRACE_ITEM: &[Field] = &[
+    ("id", "number", "the <trace_id> of `scout trace`"),
+    ("time", "string", "ISO 8601"),
+    ("total_call_time", "number", "ms"),
+    ("metric_name", "string", "endpoint name"),
+    ("uri", "string", ""),
+    ("link", "string", ""),
+];
+
+const TRACE: &[Field] = &[
+    ("id", "number", ""),
+    ("time", "string", "ISO 8601"),
+    ("total_call_time", "number", "ms"),
+    ("metric_name", "string", "endpoint name"),
+    ("uri", "string", ""),
+    (
+        "limited",
+        "boolean",
+        "the agent stopped recording spans part-way",
+    ),
+    (
+        "spans",
+        "array",
+        "objects with id, parent_id, name, duration (ms), start_time and optional children",
+    ),
+];
+
+const ERROR_GROUP: &[Field] = &[
+    ("id", "number", "the <error_id> of `scout error`"),
+    ("name", "string", "exception class"),
+    ("message", "string", ""),
+    ("request_uri", "string", ""),
+    ("last_seen", "string", "ISO 8601; used by --active-since"),
+    ("link", "string", ""),
+    (
+        "share_pct",
+        "number",
+        "percent of all listed occurrences (`errors` only; absent without counts)",
+    ),
+];
+
+const ERROR: &[Field] = &[
+    ("id", "number", ""),
+    ("created_at", "string", "ISO 8601"),
+    ("message", "string", ""),
+    ("location", "string", "file:line"),
+    ("request_uri", "string", ""),
+    ("backtrace", "array", "strings, innermost frame first"),
+];
+
+const INSIGHTS: &[Field] = &[
+    ("n_plus_one", "array", "N+1 query insights"),
+    ("memory_bloat", "array", "memory bloat insights"),
+    ("slow_query", "array", "slow query insights"),
+];
+
+const PARSED_URL: &[Field] = &[
+    (
+        "url_type",
+        "string",
+        "app, endpoint, trace, error_group, insight or metric",
+    ),
+    ("app_id", "number | null", ""),
+    ("endpoint_id", "string | null", "base64url endpoint id"),
+    ("trace_id", "number | null", ""),
+    ("error_id", "number | null", ""),
+    ("insight_type", "string | null", ""),
+    (
+        "decoded_endpoint",
+        "string | null",
+        "endpoint name decoded from endpoint_id",
+    ),
+    (
+        "metric_type",
+        "string | null",
+        "segment after /metrics/ or /charts/",
+    ),
+];
+
+const ENDPOINT_COMPARISON: &[Field] = &[
+    ("app_id", "number", "one row per --apps id, in that order"),
+    (
+        "endpoint",
+        "string | null",
+        "the matched endpoint name in this app",
+    ),
+    ("summary", "object | null", "as metric-summary"),
+    (
+        "note",
+        "string",
+        "only without a summary: endpoint not found, ambiguous, or no data",
+    ),
+];
+
+const DASHBOARD: &[Field] = &[
+    ("app_id", "number", ""),
+    ("from", "string", "ISO 8601 start of the window"),
+    ("to", "string", "ISO 8601 end of the window (now)"),
+    (
+        "metrics",
+        "array",
+        "{metric_type, summary (as metric-summary) or null, error when there was no data}",
+    ),
+    (
+        "top_errors",
+        "array",
+        "up to 5 error groups (as errors), most occurrences first",
+    ),
+    ("insights", "object", "as insights, up to 5 per type"),
+];
+
+/// Commands (as typed on the command line) and the fields of each item / object they print.
+const SHAPES: &[(&[&str], &[Field])] = &[
+    (&["apps", "app"], APP),
+    (
+        &[
+            "metric",
+            "metrics-batch",
+            "endpoint-metric",
+            "endpoint-metric-by-name",
+        ],
+        SERIES,
+    ),
+    (&["metric-summary"], SUMMARY),
+    (&["endpoint-metric-diff"], DELTA),
+    (&["endpoints"], ENDPOINT),
+    (&["endpoint-traces", "slow-traces"], TRACE_ITEM),
+    (&["trace"], TRACE),
+    (&["errors", "error"], ERROR_GROUP),
+    (&["error-group-errors"], ERROR),
+    (&["insights"], INSIGHTS),
+    (&["dashboard"], DASHBOARD),
+    (&["compare-endpoint"], ENDPOINT_COMPARISON),
+    (&["parse-url"], PARSED_URL),
+];
+
+/// Commands `explain` knows, in table order.
+pub fn commands() -> Vec<&'static str> {
+    SHAPES
+        .iter()
+        .flat_map(|(cmds, _)| cmds.iter().copied())
+        .collect()
+}
+
+/// Documented fields of `command`'s output as `[{field, type, note}]`, or `None` for an unknown command.
+pub fn explain(command: &str) -> Option<Value> {
+    let (_, fields) = SHAPES.iter().find(|(cmds, _)| cmds.contains(&command))?;
+    Some(Value::Array(
+        fields
+            .iter()
+            .map(|(field, ty, note)| serde_json::json!({"field": field, "type": ty, "note": note}))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn explained_commands_exist() {
+        let cli = crate::Cli::command();
+        for name in commands() {
+            assert!(cli.find_subcommand(name).is_some(), "no subcommand {name}");
+        }
+    }
+
+    #[test]
+    fn explain_apps() {
+        let fields = explain("apps").unwrap();
+        assert_eq!(fields[0]["field"], "id");
+        assert_eq!(fields[0]["type"], "number");
+        assert!(explain("nope").is_none());
+    }
+}
@@ -1,5 +1,7 @@
-//! Output formatting: plain text (human-readable) and JSON.
+//! Output formatting: plain text (human-readable), JSON, NDJSON, Markdown and Prometheus text.
 
+use scout_lib::models::{Span, Trace};
+use scout_lib::Error;
 use serde_json::Value;
 use std::fmt::Write;
 
@@ -10,6 +12,14 @@ pub enum OutputFormat {
     Plain,
     /// JSON (pretty-printed)
     Json,
+    /// JSON on a single line (`-o json --compact`; not selectable by name)
+    JsonCompact,
+    /// Newline-delimited JSON: one compact value per line (array elements are split into lines)
+    Ndjson,
+    /// GitHub-flavored Markdown (tables for arrays of objects, bullet lists otherwise)
+    Markdown,
+    /// Prometheus text exposition format (metric series only: latest value as a gauge)
+    Prometheus,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -18,6 +28,9 @@ impl std::str::FromStr for OutputFormat {
         match s.to_lowercase().as_str() {
             "plain" | "text" | "p" => Ok(OutputFormat::Plain),
             "json" | "j" => Ok(OutputFormat::Json),
+            "ndjson" | "jsonl" | "json-lines" => Ok(OutputFormat::Ndjson),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            "prometheus" | "prom" => Ok(OutputFormat::Prometheus),
             _ => Err(format!("unknown output format: {}", s)),
         }
     }
@@ -54,29 +67,7 @@ fn format_plain_impl(v: &Value, out: &mut String, indent: usize) {
             if first.is_object() && arr.len() > 1 {
                 let keys = object_keys(first);
                 if !keys.is_empty() {
-                    let header: String = keys
-                        .iter()
-                        .map(|k| format!("{:>12}", k))
-                        .collect::<Vec<_>>()
-                        .join(" ");
-                    let _ = writeln!(out, "{}{}", pad, header);
-                    let _ = writeln!(out, "{}{}", pad, "-".repeat(header.len().min(80)));
-                    for obj in arr {
-                        if let Value::Object(m) = obj {
-                            let row: String = keys
-                                .iter()
-                                .map(|k| {
-                                    let val = m
-                                        .get(k)
-                                        .and_then(as_short_str)
-                                        .unwrap_or_else(|| "-".to_string());
-                                    format!("{:>12}", truncate(val.as_str(), 12))
-                                })
-                                .collect::<Vec<_>>()
-                                .join(" ");
-                            let _ = writeln!(out, "{}{}", pad, row);
-                        }
-                    }
+                    format_plain_table(arr, &keys, &pad, out);
                     return;
                 }
             }
@@ -85,7 +76,8 @@ fn format_plain_impl(v: &Value, out: &mut String, indent: usize) {
                     let _ = writeln!(out, "{}[{}]", pad, i + 1);
                     format_plain_impl(item, out, indent + 1);
                 } else {
-                    let _ = writeln!(out, "{}{}", pad, item);
+                    let s = as_short_str(item).unwrap_or_default();
+                    let _ = writeln!(out, "{}{}", pad, s);
                 }
             }
         }
@@ -103,6 +95,78 @@ fn format_plain_impl(v: &Value, out: &mut String, indent: usize) {
     }
 }
 
+/// Max width of a plain table column; longer cells are truncated with `…`.
+const COLUMN_MAX: usize = 40;
+
+/// Plain table with per-column widths fitted to the data (capped at [`COLUMN_MAX`]).
+/// Numeric columns are right-aligned, everything else left-aligned.
+fn format_plain_table(rows: &[Value], keys: &[String], pad: &str, out: &mut String) {
+    let cells: Vec<Vec<(String, bool)>> = rows
+        .iter()
+        .filter_map(Value::as_object)
+        .map(|m| {
+            keys.iter()
+                .map(|k| match m.get(k).filter(|v| !v.is_null()) {
+                    Some(v) => (
+                        truncate(
+                            &as_short_str(v).unwrap_or_else(|| v.to_string()),
+                            COLUMN_MAX,
+                        ),
+                        v.is_number(),
+                    ),
+                    None => ("-".to_string(), false),
+                })
+                .collect()
+        })
+        .collect();
+    let columns: Vec<(usize, bool)> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, k)| {
+            let width = cells
+                .iter()
+                .map(|row| row[i].0.chars().count())
+                .chain(std::iter::once(k.chars().count().min(COLUMN_MAX)))
+                .max()
+                .unwrap_or(0);
+            // Right-align when every present value is a number.
+            let numeric = cells.iter().any(|row| row[i].1)
+                && cells.iter().all(|row| row[i].1 || row[i].0 == "-");
+            (width, numeric)
+        })
+        .collect();
+    let render = |row: &mut dyn Iterator<Item = String>| -> String {
+        row.zip(&columns)
+            .map(|(cell, &(width, numeric))| {
+                if numeric {
+                    format!("{:>width$}", cell)
+                } else {
+                    format!("{:<width$}", cell)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    let header = render(&mut keys.iter().map(|k| truncate(k, COLUMN_MAX)));
+    let rule_len = columns
+        .iter()
+        .map(|(w, _)| w + 2)
+        .sum::<usize>()
+        .saturating_sub(2);
+    let _ = writeln!(out, "{}{}", pad, header);
+    let _ = writeln!(out, "{}{}", pad, "-".repeat(rule_len));
+    for row in cells {
+        let _ = writeln!(
+            out,
+            "{}{}",
+            pad,
+            render(&mut row.into_iter().map(|(c, _)| c))
+        );
+    }
+}
+
 fn object_keys(obj: &Value) -> Vec<String> {
     obj.as_object()
         .map(|m| m.keys().map(String::clone).collect::<Vec<_>>())
@@ -119,30 +183,522 @@ fn as_short_str(v: &Value) -> Option<String> {
     }
 }
 
-fn truncate(s: &str, max: usize) -> String {
+pub fn truncate(s: &str, max: usize) -> String {
     let s = s.replace('\n', " ");
-    if s.len() <= max {
+    if s.chars().count() <= max {
         s
     } else {
-        format!("{}…", &s[..max.saturating_sub(1)])
+        let kept: String = s.chars().take(max.saturating_sub(1)).collect();
+        format!("{}…", kept)
+    }
+}
+
+/// Max characters in a Markdown table cell before truncation.
+const MARKDOWN_CELL_MAX: usize = 80;
+
+/// Format value as GitHub-flavored Markdown: a table for arrays of objects (columns from the first object),
+/// a bulleted key/value list for objects and arrays, and scalars as-is.
+pub fn format_markdown(value: &Value) -> String {
+    let mut out = String::new();
+    match value {
+        Value::Array(arr) if arr.first().is_some_and(Value::is_object) => {
+            let keys = object_keys(&arr[0]);
+            let header: Vec<String> = keys.iter().map(|k| markdown_escape(k)).collect();
+            let _ = writeln!(out, "| {} |", header.join(" | "));
+            let _ = writeln!(out, "|{}", " --- |".repeat(keys.len()));
+            for obj in arr {
+                let row: Vec<String> = keys
+                    .iter()
+                    .map(|k| obj.get(k).map(markdown_cell).unwrap_or_default())
+                    .collect();
+                let _ = writeln!(out, "| {} |", row.join(" | "));
+            }
+        }
+        Value::Array(_) | Value::Object(_) => format_markdown_list(value, &mut out, 0),
+        other => {
+            let _ = writeln!(out, "{}", as_short_str(other).unwrap_or_default());
+        }
+    }
+    out
+}
+
+fn format_markdown_list(v: &Value, out: &mut String, indent: usize) {
+    let pad = "  ".repeat(indent);
+    let entries: Vec<(Option<&str>, &Value)> = match v {
+        Value::Object(map) => map.iter().map(|(k, v)| (Some(k.as_str()), v)).collect(),
+        Value::Array(arr) => arr.iter().map(|v| (None, v)).collect(),
+        _ => return,
+    };
+    if entries.is_empty() {
+        let _ = writeln!(out, "{}- _empty_", pad);
+    }
+    for (key, val) in entries {
+        let label = key.map(|k| format!("**{}**:", markdown_escape(k)));
+        if val.is_object() || val.is_array() {
+            let _ = writeln!(out, "{}- {}", pad, label.unwrap_or_default());
+            format_markdown_list(val, out, indent + 1);
+        } else {
+            let text = markdown_cell(val);
+            match label {
+                Some(label) => _ = writeln!(out, "{}- {} {}", pad, label, text),
+                None => _ = writeln!(out, "{}- {}", pad, text),
+            }
+        }
     }
 }
 
+/// Render one value for a Markdown cell: scalars via `as_short_str`, nested values as compact JSON, truncated and escaped.
+fn markdown_cell(v: &Value) -> String {
+    let s = as_short_str(v).unwrap_or_else(|| v.to_string());
+    markdown_escape(&truncate(&s, MARKDOWN_CELL_MAX))
+}
+
+fn markdown_escape(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
 /// Format value as JSON (pretty).
 pub fn format_json(value: &Value) -> Result<String, serde_json::Error> {
     serde_json::to_string_pretty(value)
 }
 
 /// Format value as JSON (compact). Use for machine output.
-#[allow(dead_code)]
 pub fn format_json_compact(value: &Value) -> Result<String, serde_json::Error> {
     serde_json::to_string(value)
 }
 
+/// Format value as NDJSON: one compact JSON line per element of a top-level array, otherwise a single line.
+/// Every line (including the last) ends with a newline; an empty array yields an empty string.
+pub fn format_ndjson(value: &Value) -> Result<String, serde_json::Error> {
+    let mut out = String::new();
+    let items = match value {
+        Value::Array(arr) => arr.as_slice(),
+        other => std::slice::from_ref(other),
+    };
+    for item in items {
+        out.push_str(&serde_json::to_string(item)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Latest value of a metric series as a Prometheus gauge with HELP/TYPE comments, e.g.
+/// `scout_response_time{app="123"} 12.5 1736899200000`. The sample timestamp (ms) is omitted when the
+/// point's time cannot be parsed; an empty series yields only the comments.
+pub fn format_prometheus(app_id: u64, metric_type: &str, series: &Value) -> String {
+    let name = format!("scout_{}", metric_type);
+    let mut out = format!(
+        "# HELP {name} Scout APM {metric_type} (latest point of the series)\n# TYPE {name} gauge\n"
+    );
+    let latest = scout_lib::series_points(series)
+        .into_iter()
+        .max_by(|a, b| a.0.cmp(&b.0));
+    if let Some((ts, value)) = latest {
+        let _ = write!(out, "{}{{app=\"{}\"}} {}", name, app_id, value);
+        if let Ok(t) = chrono::DateTime::parse_from_rfc3339(&ts) {
+            let _ = write!(out, " {}", t.timestamp_millis());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Buckets [`format_histogram`] spreads raw samples over.
+const HISTOGRAM_BINS: usize = 10;
+/// Width in characters of the longest histogram bar.
+const HISTOGRAM_BAR_WIDTH: usize = 40;
+
+/// `(label, count)` buckets of a distribution: `[bound, count]` pairs, objects with a `count` (or `value`)
+/// and `min`/`max` (or `from`/`to`, or a single `bucket`) bounds, or plain samples binned into
+/// [`HISTOGRAM_BINS`] equal-width buckets. The list may also sit under a `buckets` key.
+fn histogram_buckets(v: &Value) -> Vec<(String, f64)> {
+    let items = match v.get("buckets").unwrap_or(v) {
+        Value::Array(items) => items,
+        _ => return Vec::new(),
+    };
+    let label = |v: Option<&Value>| v.and_then(as_short_str).unwrap_or_else(|| "?".to_string());
+    if items.iter().all(Value::is_number) {
+        let samples: Vec<f64> = items.iter().filter_map(Value::as_f64).collect();
+        let (Some(min), Some(max)) = (
+            samples.iter().copied().reduce(f64::min),
+            samples.iter().copied().reduce(f64::max),
+        ) else {
+            return Vec::new();
+        };
+        let bins = if max > min { HISTOGRAM_BINS } else { 1 };
+        let width = (max - min) / bins as f64;
+        let mut counts = vec![0.0; bins];
+        for s in samples {
+            let i = if width > 0.0 {
+                (((s - min) / width) as usize).min(bins - 1)
+            } else {
+                0
+            };
+            counts[i] += 1.0;
+        }
+        return counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| {
+                let lo = min + width * i as f64;
+                (format!("{:.1}-{:.1}", lo, lo + width), n)
+            })
+            .collect();
+    }
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Value::Array(pair) if pair.len() == 2 => Some((label(pair.first()), pair[1].as_f64()?)),
+            Value::Object(obj) => {
+                let count = obj.get("count").or_else(|| obj.get("value"))?.as_f64()?;
+                let bounds = match (
+                    obj.get("min").or_else(|| obj.get("from")),
+                    obj.get("max").or_else(|| obj.get("to")),
+                ) {
+                    (Some(lo), Some(hi)) => format!("{}-{}", label(Some(lo)), label(Some(hi))),
+                    _ => label(obj.get("bucket")),
+                };
+                Some((bounds, count))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// A distribution as an ASCII histogram: one line per bucket with its label, a bar scaled to the largest
+/// bucket and the count. See [`histogram_buckets`] for the accepted shapes.
+pub fn format_histogram(v: &Value) -> String {
+    let buckets = histogram_buckets(v);
+    if buckets.is_empty() {
+        return "(no data)\n".to_string();
+    }
+    let label_width = buckets
+        .iter()
+        .map(|(l, _)| l.chars().count())
+        .max()
+        .unwrap_or(0);
+    let max = buckets.iter().map(|(_, n)| *n).fold(0.0, f64::max);
+    let mut out = String::new();
+    for (label, n) in &buckets {
+        let len = if max > 0.0 {
+            (n / max * HISTOGRAM_BAR_WIDTH as f64).round() as usize
+        } else {
+            0
+        };
+        let _ = writeln!(
+            out,
+            "{:>w$} | {} {}",
+            label,
+            "█".repeat(len),
+            n,
+            w = label_width
+        );
+    }
+    out
+}
+
+/// A trace as an indented span tree: a header line (endpoint, total time, start, URI), then every span with
+/// its duration and share of its parent's duration, children ordered by start time. Spans may nest via
+/// `children` arrays or be flat with `id`/`parent_id`.
+pub fn format_trace_tree(trace: &Value) -> String {
+    let trace = Trace::from(trace);
+    let total = trace.total_duration_ms();
+    let mut out = String::new();
+    let _ = write!(out, "{}", trace.metric_name.as_deref().unwrap_or("trace"));
+    if let Some(t) = total {
+        let _ = write!(out, "  {:.1} ms", t);
+    }
+    for s in [&trace.time, &trace.uri].into_iter().flatten() {
+        let _ = write!(out, "  {}", s);
+    }
+    out.push('\n');
+
+    if trace.spans.is_empty() {
+        out.push_str("  (no spans)\n");
+        return out;
+    }
+    write_spans(&trace, trace.roots(), total, "", &mut out);
+    out
+}
+
+fn write_spans(
+    trace: &Trace,
+    spans: Vec<&Span>,
+    parent_ms: Option<f64>,
+    prefix: &str,
+    out: &mut String,
+) {
+    let count = spans.len();
+    for (i, span) in spans.into_iter().enumerate() {
+        let last = i + 1 == count;
+        let _ = write!(
+            out,
+            "{}{}{}",
+            prefix,
+            if last { "└─ " } else { "├─ " },
+            span.name.as_deref().unwrap_or("?")
+        );
+        if let Some(d) = span.duration_ms {
+            let _ = write!(out, "  {:.1} ms", d);
+            if let Some(p) = parent_ms.filter(|p| *p > 0.0) {
+                let _ = write!(out, "  ({:.1}%)", d / p * 100.0);
+            }
+        }
+        out.push('\n');
+        let child_prefix = format!("{}{}", prefix, if last { "   " } else { "│  " });
+        write_spans(
+            trace,
+            trace.children(span),
+            span.duration_ms,
+            &child_prefix,
+            out,
+        );
+    }
+}
+
+/// Extract a sortable time string from a Value (ISO 8601 or similar). Tries common field names.
+pub fn time_sort_key(v: &Value) -> String {
+    v.get("last_seen")
+        .or_else(|| v.get("first_seen"))
+        .or_else(|| v.get("timestamp"))
+        .or_else(|| v.get("created_at"))
+        .or_else(|| v.get("time"))
+        .or_else(|| v.get("reported_at"))
+        .and_then(|t| t.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+/// The list in a result: a top-level array, or the array under `key` (e.g. `{"endpoints": [...]}`).
+pub fn result_list_mut<'a>(v: &'a mut Value, key: &str) -> Option<&'a mut Vec<Value>> {
+    if v.is_array() {
+        return v.as_array_mut();
+    }
+    v.get_mut(key).and_then(|a| a.as_array_mut())
+}
+
+/// Order `items` latest first (by [`time_sort_key`], as the TUI lists them) and keep at most `limit`.
+/// Items without a time keep their relative order after the dated ones.
+pub fn latest_first(items: &mut Vec<Value>, limit: Option<usize>) {
+    items.sort_by_key(|v| std::cmp::Reverse(time_sort_key(v)));
+    if let Some(n) = limit {
+        items.truncate(n);
+    }
+}
+
+/// Sort objects by `field`: numbers numerically, strings lexically (numbers before strings). Items
+/// without the field (or with null) stay last in either direction; the sort is stable.
+pub fn sort_values(arr: &mut [Value], field: &str, desc: bool) {
+    let rank = |v: &Value| match v.get(field) {
+        Some(Value::Number(_)) => 0,
+        Some(Value::String(_)) => 1,
+        Some(Value::Null) | None => 3,
+        Some(_) => 2,
+    };
+    arr.sort_by(|a, b| {
+        let (ra, rb) = (rank(a), rank(b));
+        if ra != rb || ra == 3 {
+            return ra.cmp(&rb);
+        }
+        let ord = match (&a[field], &b[field]) {
+            (Value::Number(x), Value::Number(y)) => x
+                .as_f64()
+                .unwrap_or(0.0)
+                .total_cmp(&y.as_f64().unwrap_or(0.0)),
+            (Value::String(x), Value::String(y)) => x.cmp(y),
+            (x, y) => x.to_string().cmp(&y.to_string()),
+        };
+        if desc {
+            ord.reverse()
+        } else {
+            ord
+        }
+    });
+}
+
+/// The value at a dotted path such as `results.0.name` or `series[2].value`: object keys by name, array
+/// items by index (`.0` or `[0]`). An empty path is the value itself; `None` when any step is missing.
+pub fn select_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.replace('[', ".").replace(']', "");
+    let path = path.trim_start_matches('.');
+    if path.is_empty() {
+        return Some(value);
+    }
+    path.split('.').try_fold(value, |v, step| match v {
+        Value::Object(obj) => obj.get(step),
+        Value::Array(arr) => step.parse::<usize>().ok().and_then(|i| arr.get(i)),
+        _ => None,
+    })
+}
+
+/// Status code and response body carried by an API error, if any.
+fn error_details(e: &Error) -> (Option<u16>, Option<&Value>) {
+    match e {
+        Error::Api(api) => (api.status_code, api.response_data.as_ref()),
+        Error::Auth(_) => (Some(401), None),
+        Error::NotFound { .. } => (Some(404), None),
+        Error::Other(_) => (None, None),
+    }
+}
+
+/// Error as a JSON object for machine-readable output: `{error, kind, status_code, response_data}`
+/// (`kind` is the `ApiErrorKind` name of API errors, `not_found` for not-found errors, else null).
+pub fn error_json(e: &Error) -> Value {
+    let (status_code, response_data) = error_details(e);
+    let kind = match e {
+        Error::Api(api) => Some(api.kind().as_str()),
+        Error::NotFound { .. } => Some("not_found"),
+        _ => None,
+    };
+    serde_json::json!({
+        "error": e.to_string(),
+        "kind": kind,
+        "status_code": status_code,
+        "response_data": response_data,
+    })
+}
+
+/// Error message for plain output, with the HTTP status appended when known, e.g. `API error: Not found (404)`.
+pub fn error_message(e: &Error) -> String {
+    match error_details(e) {
+        (Some(code), _) => format!("{} ({})", e, code),
+        (None, _) => e.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn select_path_follows_keys_and_indexes() {
+        let v = serde_json::json!({
+            "results": [{"name": "a"}, {"name": "b", "tags": ["x", "y"]}],
+            "series": {"points": [[1, 2]]},
+        });
+        assert_eq!(
+            select_path(&v, "results.1.name"),
+            Some(&serde_json::json!("b"))
+        );
+        assert_eq!(
+            select_path(&v, "results[1].tags[0]"),
+            Some(&serde_json::json!("x"))
+        );
+        assert_eq!(
+            select_path(&v, "series.points"),
+            Some(&serde_json::json!([[1, 2]]))
+        );
+        assert_eq!(select_path(&v, ""), Some(&v));
+        assert_eq!(select_path(&v, "results.2.name"), None);
+        assert_eq!(select_path(&v, "results.first"), None);
+        assert_eq!(select_path(&v, "results..name"), None);
+        assert_eq!(select_path(&v, "series.points.0.0.x"), None);
+    }
+
+    #[test]
+    fn latest_first_sorts_and_limits() {
+        let mut v = serde_json::json!({"endpoints": [
+            {"name": "a", "last_seen": "2025-01-01T00:00:00Z"},
+            {"name": "b"},
+            {"name": "c", "last_seen": "2025-01-03T00:00:00Z"},
+        ]});
+        let items = result_list_mut(&mut v, "endpoints").unwrap();
+        latest_first(items, Some(2));
+        let names: Vec<&str> = items.iter().map(|i| i["name"].as_str().unwrap()).collect();
+        assert_eq!(names, ["c", "a"]);
+        assert!(result_list_mut(&mut serde_json::json!({}), "endpoints").is_none());
+    }
+
+    #[test]
+    fn sort_values_numbers_strings_and_missing() {
+        let mut items: Vec<Value> = serde_json::from_str(
+            r#"[{"t": 2}, {"t": "b"}, {}, {"t": 10}, {"t": null}, {"t": "a"}]"#,
+        )
+        .unwrap();
+        sort_values(&mut items, "t", false);
+        let order: Vec<String> = items.iter().map(|i| i["t"].to_string()).collect();
+        assert_eq!(order, ["2", "10", "\"a\"", "\"b\"", "null", "null"]);
+        sort_values(&mut items, "t", true);
+        let order: Vec<String> = items.iter().map(|i| i["t"].to_string()).collect();
+        assert_eq!(order, ["10", "2", "\"b\"", "\"a\"", "null", "null"]);
+    }
+
+    #[test]
+    fn format_trace_tree_flat_spans() {
+        let trace = serde_json::json!({
+            "metric_name": "Controller/UsersController#index",
+            "total_call_time": 200.0,
+            "spans": [
+                {"id": 3, "parent_id": 1, "name": "View/index", "duration": 20.0, "start_time": 2},
+                {"id": 1, "name": "Controller", "duration": 200.0, "start_time": 0},
+                {"id": 2, "parent_id": 1, "name": "SQL/User/find", "duration": 50.0, "start_time": 1},
+            ],
+        });
+        assert_eq!(
+            format_trace_tree(&trace),
+            "Controller/UsersController#index  200.0 ms\n\
+             └─ Controller  200.0 ms  (100.0%)\n   \
+             ├─ SQL/User/find  50.0 ms  (25.0%)\n   \
+             └─ View/index  20.0 ms  (10.0%)\n"
+        );
+    }
+
+    #[test]
+    fn format_trace_tree_nested_children() {
+        let trace = serde_json::json!({
+            "spans": [{"name": "a", "duration": 10.0, "children": [
+                {"name": "b", "duration": 4.0, "children": [{"name": "c"}]},
+            ]}],
+        });
+        let out = format_trace_tree(&trace);
+        assert!(out.contains("   └─ b  4.0 ms  (40.0%)\n"), "{out}");
+        assert!(out.contains("      └─ c\n"), "{out}");
+        assert!(format_trace_tree(&serde_json::json!({})).contains("(no spans)"));
+    }
+
+    #[test]
+    fn format_histogram_shapes() {
+        let pairs = serde_json::json!([[100, 4], [200, 2], [400, 0]]);
+        assert_eq!(
+            format_histogram(&pairs),
+            format!(
+                "100 | {} 4\n200 | {} 2\n400 |  0\n",
+                "█".repeat(40),
+                "█".repeat(20)
+            )
+        );
+        let objects = serde_json::json!({"buckets": [{"min": 0, "max": 50, "count": 1}]});
+        assert!(format_histogram(&objects).starts_with("0-50 | "));
+        let samples = serde_json::json!([1.0, 1.0, 10.0]);
+        let out = format_histogram(&samples);
+        assert_eq!(out.lines().count(), HISTOGRAM_BINS);
+        assert!(out.lines().next().unwrap().ends_with(" 2"));
+        assert!(out.lines().last().unwrap().ends_with(" 1"));
+        assert_eq!(format_histogram(&serde_json::json!([])), "(no data)\n");
+    }
+
+    #[test]
+    fn format_prometheus_latest_point() {
+        let series = serde_json::json!({"response_time": [
+            ["2025-01-15T00:00:00Z", 12.5],
+            ["2025-01-14T00:00:00Z", 99.0],
+        ]});
+        let out = format_prometheus(123, "response_time", &series);
+        assert_eq!(
+            out,
+            "# HELP scout_response_time Scout APM response_time (latest point of the series)\n\
+             # TYPE scout_response_time gauge\n\
+             scout_response_time{app=\"123\"} 12.5 1736899200000\n"
+        );
+    }
+
+    #[test]
+    fn format_prometheus_empty_series_has_no_samples() {
+        let out = format_prometheus(1, "errors", &serde_json::json!([]));
+        assert_eq!(out.lines().count(), 2);
+        assert!(out.lines().all(|l| l.starts_with('#')));
+    }
+
     #[test]
     fn output_format_from_str() {
         assert_eq!(
@@ -158,6 +714,14 @@ mod tests {
         assert_eq!("p".parse::<OutputFormat>().unwrap(), OutputFormat::Plain);
         assert_eq!("j".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
         assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Plain);
+        assert_eq!(
+            "ndjson".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Ndjson
+        );
+        assert_eq!(
+            "md".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Markdown
+        );
         assert!("xml".parse::<OutputFormat>().is_err());
     }
 
@@ -207,6 +771,46 @@ mod tests {
         assert!(out.contains("b"));
     }
 
+    #[test]
+    fn format_plain_table_null_cell_is_dash() {
+        let v = serde_json::json!([
+            {"id": 1, "name": null},
+            {"id": 2, "name": "b"}
+        ]);
+        let out = format_plain(&v);
+        assert!(!out.contains("null"));
+        assert!(out.lines().nth(2).unwrap().trim_end().ends_with('-'));
+    }
+
+    #[test]
+    fn format_plain_table_fits_columns_to_content() {
+        let v = serde_json::json!([
+            {"name": "/api/v2/users/profile", "throughput": 123456},
+            {"name": "/a", "throughput": 7}
+        ]);
+        let out = format_plain(&v);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "name                   throughput");
+        assert_eq!(lines[2], "/api/v2/users/profile      123456");
+        assert_eq!(lines[3], "/a                              7");
+    }
+
+    #[test]
+    fn format_plain_table_caps_column_width() {
+        let long = "x".repeat(100);
+        let v = serde_json::json!([{"name": long}, {"name": "y"}]);
+        let out = format_plain(&v);
+        let row = out.lines().nth(2).unwrap();
+        assert_eq!(row.chars().count(), COLUMN_MAX);
+        assert!(row.ends_with('…'));
+    }
+
+    #[test]
+    fn format_plain_array_of_strings_unquoted() {
+        let out = format_plain(&serde_json::json!(["apdex", "throughput"]));
+        assert_eq!(out, "apdex\nthroughput\n");
+    }
+
     #[test]
     fn format_json_roundtrip() {
         let v = serde_json::json!({"x": 1, "y": [2, 3]});
@@ -214,4 +818,87 @@ mod tests {
         let parsed: Value = serde_json::from_str(&s).unwrap();
         assert_eq!(parsed, v);
     }
+
+    #[test]
+    fn format_ndjson_one_line_per_array_element() {
+        let v = serde_json::json!([{"id": 1}, {"id": 2, "tags": ["a", "b"]}, "x"]);
+        let out = format_ndjson(&v).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for (line, expected) in lines.iter().zip(v.as_array().unwrap()) {
+            assert_eq!(&serde_json::from_str::<Value>(line).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn format_ndjson_non_array_is_single_line() {
+        let v = serde_json::json!({"x": 1, "y": [2, 3]});
+        let out = format_ndjson(&v).unwrap();
+        assert_eq!(out, "{\"x\":1,\"y\":[2,3]}\n");
+        assert_eq!(format_ndjson(&serde_json::json!([])).unwrap(), "");
+    }
+
+    #[test]
+    fn format_markdown_table() {
+        let v = serde_json::json!([
+            {"id": 1, "name": "GET /a|b"},
+            {"id": 2, "name": null}
+        ]);
+        let out = format_markdown(&v);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "| id | name |");
+        assert_eq!(lines[1], "| --- | --- |");
+        assert_eq!(lines[2], "| 1 | GET /a\\|b |");
+        assert_eq!(lines[3], "| 2 | null |");
+    }
+
+    #[test]
+    fn format_markdown_object_and_scalar() {
+        let v = serde_json::json!({"name": "scout", "tags": ["a"]});
+        let out = format_markdown(&v);
+        assert!(out.contains("- **name**: scout"));
+        assert!(out.contains("- **tags**:\n  - a"));
+        assert_eq!(format_markdown(&serde_json::json!(42)), "42\n");
+    }
+
+    #[test]
+    fn truncate_is_char_boundary_safe() {
+        assert_eq!(truncate("ääää", 3), "ää…");
+        assert_eq!(truncate("abc", 3), "abc");
+    }
+
+    #[test]
+    fn error_json_carries_api_fields() {
+        let body = serde_json::json!({"header": {"status": {"code": 422, "message": "bad from"}}});
+        let e = Error::Api(scout_lib::ApiError::new(
+            "bad from",
+            Some(422),
+            Some(body.clone()),
+        ));
+        let v = error_json(&e);
+        assert_eq!(v["error"], "API error: bad from");
+        assert_eq!(v["kind"], "invalid_param");
+        assert_eq!(v["status_code"], 422);
+        assert_eq!(v["response_data"], body);
+
+        let other = error_json(&Error::Other("boom".to_string()));
+        assert_eq!(other["error"], "boom");
+        assert!(other["kind"].is_null());
+        assert!(other["status_code"].is_null());
+        assert!(other["response_data"].is_null());
+
+        let missing = error_json(&Error::NotFound {
+            resource: "trace 5 in app 9".to_string(),
+        });
+        assert_eq!(missing["error"], "trace 5 in app 9 not found");
+        assert_eq!(missing["kind"], "not_found");
+        assert_eq!(missing["status_code"], 404);
+    }
+
+    #[test]
+    fn error_message_appends_status_code() {
+        let e = Error::Api(scout_lib::ApiError::new("Server error", Some(500), None));
+        assert_eq!(error_message(&e), "API error: Server error (500)");
+        assert_eq!(error_message(&Error::Other("boom".to_string())), "boom");
+    }
 }
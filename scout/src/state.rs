@@ -0,0 +1,65 @@
+//! Small TUI state kept between sessions (`$XDG_CACHE_HOME/scout/state.json` or `~/.cache/scout/state.json`).
+//! Best effort: a missing, unreadable or corrupt file is treated as empty and write failures are ignored.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct State {
+    /// Id of the app opened last; the app picker starts on it.
+    pub last_app: Option<u64>,
+}
+
+/// State file location: `$XDG_CACHE_HOME/scout/state.json`, else `~/.cache/scout/state.json`.
+fn state_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))?;
+    Some(base.join("scout").join("state.json"))
+}
+
+/// Load the saved state; empty when the file is missing or unreadable.
+pub fn load() -> State {
+    state_path()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .map(|text| parse(&text))
+        .unwrap_or_default()
+}
+
+/// Remember `app_id` as the last opened app.
+pub fn save_last_app(app_id: u64) {
+    let Some(path) = state_path() else {
+        return;
+    };
+    let state = State {
+        last_app: Some(app_id),
+    };
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(text) = serde_json::to_string(&state) {
+        let _ = std::fs::write(path, text);
+    }
+}
+
+fn parse(text: &str) -> State {
+    serde_json::from_str(text).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_state() {
+        assert_eq!(parse(r#"{"last_app": 42}"#).last_app, Some(42));
+        assert_eq!(parse("{}"), State::default());
+    }
+
+    #[test]
+    fn parse_ignores_corrupt_state() {
+        assert_eq!(parse("not json"), State::default());
+        assert_eq!(parse(r#"{"last_app": "x"}"#), State::default());
+    }
+}
@@ -1,35 +1,108 @@
-//! Interactive TUI: app-scoped view with breadcrumbs and tabs (Endpoints, Insights, Metrics, Errors).
+//! Interactive TUI: app-scoped view with breadcrumbs and tabs (Endpoints, Insights, Metrics, Errors, Traces).
 
+use crate::output;
+use crate::state;
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{self, Event, KeyCode},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
 use ratatui::{
     layout::Alignment,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::Line,
-    widgets::{Bar, BarChart, Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        Axis, Bar, BarChart, Block, Borders, Chart, Clear, Dataset, GraphType, List, ListItem,
+        ListState, Paragraph, Tabs,
+    },
     Frame, Terminal,
 };
-use scout_lib::{format_timestamp_display, helpers::calculate_range, Client};
+use scout_lib::helpers::{build_scout_url, calculate_range, ParsedScoutUrl, ScoutUrlType};
+use scout_lib::{
+    annotate_error_share, endpoint_id_of, extract_list, format_timestamp_as, series_points, Client,
+    TimeDisplay, TRACE_LIST_LIMIT,
+};
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use std::io;
+use std::path::PathBuf;
 use std::time::Instant;
 use tokio::task::JoinHandle;
-use tokio::runtime::Runtime;
-use crossterm::{
-    cursor::{Hide, Show},
-    event::{self, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
 
-/// TUI options (from --app, --tab, --refresh, --utc).
+/// TUI options (from --app, --tab, --refresh, --utc, --relative).
 #[derive(Clone)]
 pub struct Options {
     pub app: Option<String>,
     pub tab: Tab,
     pub refresh_secs: u64,
-    /// If true, show timestamps in UTC; otherwise use local timezone.
-    pub use_utc: bool,
+    /// How timestamps are shown: local timezone (default), UTC, or relative to now.
+    pub time_display: TimeDisplay,
+    /// Colors of every pane.
+    pub theme: Theme,
+}
+
+/// Named colors used by the TUI instead of literal `Color::` values, so it stays readable on light
+/// terminals and without color.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Borders, breadcrumb, tabs and charts.
+    accent: Color,
+    /// Text drawn on `accent` (selected tab, bar values).
+    on_accent: Color,
+    /// Metric values, the loading indicator and the help popup.
+    highlight: Color,
+    /// Body text.
+    text: Color,
+    /// Absent values.
+    muted: Color,
+    /// Errors and error badges.
+    error: Color,
+}
+
+impl Theme {
+    /// For dark terminal backgrounds (the default).
+    pub const DARK: Theme = Theme {
+        accent: Color::Cyan,
+        on_accent: Color::Black,
+        highlight: Color::Yellow,
+        text: Color::White,
+        muted: Color::DarkGray,
+        error: Color::Red,
+    };
+    /// For light terminal backgrounds.
+    pub const LIGHT: Theme = Theme {
+        accent: Color::Blue,
+        on_accent: Color::White,
+        highlight: Color::Magenta,
+        text: Color::Black,
+        muted: Color::Gray,
+        error: Color::Red,
+    };
+    /// No color at all (the terminal's own colors); selection is shown reversed.
+    pub const MONO: Theme = Theme {
+        accent: Color::Reset,
+        on_accent: Color::Reset,
+        highlight: Color::Reset,
+        text: Color::Reset,
+        muted: Color::Reset,
+        error: Color::Reset,
+    };
+
+    fn fg(self, color: Color) -> Style {
+        Style::default().fg(color)
+    }
+
+    /// Text on an `accent` background; reversed in [`Theme::MONO`], which has no background color.
+    fn on_accent(self) -> Style {
+        if self == Theme::MONO {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(self.on_accent).bg(self.accent)
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -38,6 +111,7 @@ pub enum Tab {
     Insights,
     Metrics,
     Errors,
+    Traces,
 }
 
 /// Restores terminal (leave alternate screen, disable raw mode, show cursor) on drop.
@@ -51,11 +125,15 @@ impl Drop for TerminalGuard {
     }
 }
 
-/// Drill-down content: preformatted text (endpoint/insight/error table) or raw metric series (formatted at draw time with terminal width).
+/// Drill-down content: preformatted text (status/loading messages), a table of one record (endpoint/insight/error/trace)
+/// with its source value, raw metric series (formatted at draw time with terminal width),
+/// or the individual errors of an error group with their own selection.
 #[derive(Clone)]
 pub enum DrillContent {
     Preformatted(String),
+    Record { value: Value, text: String },
     MetricSeries(Value),
+    ErrorList { errors: Vec<Value>, selected: usize },
 }
 
 enum TabPayload {
@@ -63,8 +141,19 @@ enum TabPayload {
     Insights(Vec<(String, Value)>),
     Metrics(Vec<String>),
     Errors(Vec<Value>),
+    Traces(Vec<Value>),
 }
 
+/// Scroll position of the detail pane. `viewport` is the visible line count from the last draw (used for paging).
+#[derive(Default)]
+struct DetailScroll {
+    offset: u16,
+    viewport: u16,
+}
+
+/// In-flight drill load (metric series, trace, or error group errors): app id, drill label, task.
+type PendingDrillLoad = (u64, String, JoinHandle<Result<DrillContent, String>>);
+
 impl Tab {
     fn as_str(self) -> &'static str {
         match self {
@@ -72,106 +161,132 @@ impl Tab {
             Tab::Insights => "Insights",
             Tab::Metrics => "Metrics",
             Tab::Errors => "Errors",
+            Tab::Traces => "Traces",
         }
     }
-    fn all() -> [Tab; 4] {
-        [Tab::Endpoints, Tab::Insights, Tab::Metrics, Tab::Errors]
+    fn all() -> [Tab; 5] {
+        [
+            Tab::Endpoints,
+            Tab::Insights,
+            Tab::Metrics,
+            Tab::Errors,
+            Tab::Traces,
+        ]
     }
 }
 
+/// Normalize a search query for [`matches_query`]: trimmed and lowercased.
+fn normalize_query(query: &str) -> String {
+    query.trim().to_lowercase()
+}
+
+/// Case-insensitive substring match of `text` against a normalized query. Empty query matches everything.
+fn matches_query(text: &str, normalized_query: &str) -> bool {
+    normalized_query.is_empty() || text.to_lowercase().contains(normalized_query)
+}
+
 /// Indices into `apps` whose name or id contains `query` (case-insensitive). Empty query = all.
 fn filtered_app_indices(apps: &[Value], query: &str) -> Vec<usize> {
-    let q = query.trim().to_lowercase();
+    let q = normalize_query(query);
     if q.is_empty() {
         return (0..apps.len()).collect();
     }
     apps.iter()
         .enumerate()
         .filter(|(_, app)| {
-            let name = app
-                .get("name")
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_lowercase();
+            let name = app.get("name").and_then(|v| v.as_str()).unwrap_or("");
             let id = app
                 .get("id")
                 .and_then(|v| v.as_u64())
                 .map(|n| n.to_string())
                 .unwrap_or_default();
-            name.contains(&q) || id.contains(&q)
+            matches_query(name, &q) || matches_query(&id, &q)
         })
         .map(|(i, _)| i)
         .collect()
 }
 
-/// Resolve --app (id or name) to (index, app_id, app_name). Returns None if not found.
-fn resolve_app(apps: &[Value], app_arg: &str) -> Option<(usize, u64, String)> {
+/// Resolve --app (id or name) to its index in `apps`. Returns None if not found.
+fn resolve_app(apps: &[Value], app_arg: &str) -> Option<usize> {
     let app_arg = app_arg.trim();
     if app_arg.is_empty() {
         return None;
     }
-    let (index, app) = if let Ok(id) = app_arg.parse::<u64>() {
-        let i = apps
-            .iter()
-            .position(|a| a.get("id").and_then(|v| v.as_u64()) == Some(id))?;
-        (i, &apps[i])
+    if let Ok(id) = app_arg.parse::<u64>() {
+        apps.iter()
+            .position(|a| a.get("id").and_then(|v| v.as_u64()) == Some(id))
     } else {
         let lower = app_arg.to_lowercase();
-        let i = apps.iter().position(|a| {
+        apps.iter().position(|a| {
             a.get("name")
                 .and_then(|v| v.as_str())
                 .map(|n| n.to_lowercase() == lower)
                 == Some(true)
-        })?;
-        (i, &apps[i])
-    };
-    let app_id = app.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+        })
+    }
+}
+
+/// Id of the app named `name` (case-insensitive, as [`resolve_app`]) for the `<APP_ID>` of CLI commands.
+/// Unknown and ambiguous names are errors listing the candidates.
+pub fn find_app_id(apps: &[Value], name: &str) -> Result<u64, String> {
+    let lower = name.trim().to_lowercase();
+    let app_name = |a: &Value| a.get("name").and_then(|v| v.as_str()).map(String::from);
+    let matches: Vec<&Value> = apps
+        .iter()
+        .filter(|a| app_name(a).is_some_and(|n| n.to_lowercase() == lower))
+        .collect();
+    match matches.as_slice() {
+        [] => Err(format!(
+            "no app named \"{}\" (apps: {})",
+            name,
+            apps.iter()
+                .filter_map(app_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        [app] => app_identity(app).map(|(id, _)| id),
+        many => Err(format!(
+            "app name \"{}\" is ambiguous (ids: {}); pass the id instead",
+            name,
+            many.iter()
+                .map(|a| a.get("id").map_or("?".to_string(), |id| id.to_string()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Id and display name of an app from the apps list. An app without a numeric `id` is an error: every
+/// request for it would go to a bogus path.
+fn app_identity(app: &Value) -> Result<(u64, String), String> {
     let name = app
         .get("name")
         .and_then(|v| v.as_str())
         .unwrap_or("?")
         .to_string();
-    Some((index, app_id, name))
-}
-
-async fn run_async<F, T>(f: F) -> Result<T, String>
-where
-    F: std::future::Future<Output = Result<T, String>> + Send + 'static,
-    T: Send + 'static,
-{
-    tokio::task::spawn_blocking(move || -> Result<T, String> {
-        let rt = Runtime::new().map_err(|e| e.to_string())?;
-        rt.block_on(f)
-    })
-    .await
-    .map_err(|e| e.to_string())?
+    let id = app
+        .get("id")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| format!("app \"{}\" has no id field", name))?;
+    Ok((id, name))
 }
 
 async fn fetch_endpoints(client: &Client, app_id: u64) -> Result<Value, String> {
-    let client = client.clone();
-    run_async(async move {
-        client
-            .list_endpoints(app_id, None, None, Some("7days"))
-            .await
-            .map_err(|e| e.to_string())
-    })
-    .await
+    client
+        .list_endpoints(app_id, None, None, Some("7days"))
+        .await
+        .map_err(|e| e.to_string())
 }
 
 async fn fetch_insights(client: &Client, app_id: u64) -> Result<Value, String> {
-    let client = client.clone();
-    run_async(async move {
-        client
-            .get_all_insights(app_id, Some(50))
-            .await
-            .map_err(|e| e.to_string())
-    })
-    .await
+    client
+        .get_all_insights(app_id, Some(50))
+        .await
+        .map_err(|e| e.to_string())
 }
 
 async fn fetch_metrics_list(client: &Client, app_id: u64) -> Result<Vec<String>, String> {
-    let client = client.clone();
-    run_async(async move { client.list_metrics(app_id).await.map_err(|e| e.to_string()) }).await
+    client.list_metrics(app_id).await.map_err(|e| e.to_string())
 }
 
 async fn fetch_metric_series(
@@ -179,27 +294,47 @@ async fn fetch_metric_series(
     app_id: u64,
     metric_type: &str,
 ) -> Result<Value, String> {
-    let client = client.clone();
-    let metric_type = metric_type.to_string();
-    run_async(async move {
-        client
-            .get_metric(app_id, &metric_type, None, None, Some("7days"))
-            .await
-            .map_err(|e| e.to_string())
-    })
-    .await
+    client
+        .get_metric(app_id, metric_type, None, None, Some("7days"))
+        .await
+        .map_err(|e| e.to_string())
 }
 
 async fn fetch_errors(client: &Client, app_id: u64) -> Result<Vec<Value>, String> {
-    let client = client.clone();
     let (from, to) = calculate_range("7days", None).map_err(|e| e.to_string())?;
-    run_async(async move {
-        client
-            .list_error_groups(app_id, Some(&from), Some(&to), None)
-            .await
-            .map_err(|e| e.to_string())
-    })
-    .await
+    client
+        .list_error_groups(app_id, Some(&from), Some(&to), None)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn fetch_endpoint_traces(
+    client: &Client,
+    app_id: u64,
+    endpoint_id: &str,
+) -> Result<Value, String> {
+    client
+        .list_endpoint_traces(app_id, endpoint_id, None, None, Some("7days"))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn fetch_error_group_errors(
+    client: &Client,
+    app_id: u64,
+    error_id: u64,
+) -> Result<Vec<Value>, String> {
+    client
+        .get_error_group_errors(app_id, error_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn fetch_trace(client: &Client, app_id: u64, trace_id: u64) -> Result<Value, String> {
+    client
+        .fetch_trace(app_id, trace_id)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Format an endpoint (or any object) as a key-value table.
@@ -236,60 +371,6 @@ fn format_endpoint_table(v: &Value) -> String {
     out
 }
 
-fn collect_series_points(v: &Value, points: &mut Vec<(String, f64)>) {
-    if let Some(arr) = v.as_array() {
-        for p in arr {
-            if let Some((ts, val)) = p.get(0).and_then(|t| t.as_str()).zip(
-                p.get(1)
-                    .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64))),
-            ) {
-                points.push((ts.to_string(), val));
-            } else if let (Some(ts), Some(val)) = (
-                p.get("timestamp")
-                    .or_else(|| p.get("time"))
-                    .and_then(|t| t.as_str()),
-                p.get("value")
-                    .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64))),
-            ) {
-                points.push((ts.to_string(), val));
-            }
-        }
-        return;
-    }
-    if let Some(obj) = v.as_object() {
-        if let Some(arr) = obj
-            .get("points")
-            .or_else(|| obj.get("data"))
-            .and_then(|a| a.as_array())
-        {
-            for p in arr {
-                if let Some((ts, val)) = p.get(0).and_then(|t| t.as_str()).zip(
-                    p.get(1)
-                        .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64))),
-                ) {
-                    points.push((ts.to_string(), val));
-                } else if let (Some(ts), Some(val)) = (
-                    p.get("timestamp")
-                        .or_else(|| p.get("time"))
-                        .and_then(|t| t.as_str()),
-                    p.get("value")
-                        .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64))),
-                ) {
-                    points.push((ts.to_string(), val));
-                }
-            }
-            return;
-        }
-        // Nested: e.g. { "response_time": { "points": [...] } } — use first child
-        for (_, child) in obj {
-            collect_series_points(child, points);
-            if !points.is_empty() {
-                return;
-            }
-        }
-    }
-}
-
 /// Unit for display per metric type (e.g. "ms", "RPM").
 fn metric_unit(metric_type: &str) -> &'static str {
     match metric_type.trim().to_lowercase().as_str() {
@@ -314,8 +395,13 @@ fn downsample_points(points: &[(String, f64)], max_count: usize) -> Vec<(String,
         .collect()
 }
 
-fn compact_time_label(ts: &str, use_utc: bool) -> String {
-    let display = format_timestamp_display(ts, use_utc);
+/// Short chart axis label (the end of the absolute time); relative display falls back to local time here.
+fn compact_time_label(ts: &str, time_display: TimeDisplay) -> String {
+    let time_display = match time_display {
+        TimeDisplay::Relative => TimeDisplay::Local,
+        other => other,
+    };
+    let display = format_timestamp_as(ts, time_display);
     let chars: Vec<char> = display.chars().collect();
     if chars.len() > 5 {
         chars[chars.len() - 5..].iter().collect()
@@ -324,30 +410,47 @@ fn compact_time_label(ts: &str, use_utc: bool) -> String {
     }
 }
 
+/// Narrowest chart area (columns) for the line chart; below this `g` falls back to the bar chart.
+const LINE_CHART_MIN_WIDTH: u16 = 40;
+/// Most rows of the time/value table under the line chart.
+const LINE_CHART_TABLE_ROWS: usize = 8;
+
 fn render_metric_chart(
     f: &mut Frame,
     content_area: ratatui::layout::Rect,
     v: &Value,
-    use_utc: bool,
+    time_display: TimeDisplay,
     metric_type: Option<&str>,
+    line_chart: bool,
+    theme: Theme,
 ) {
-    let mut points: Vec<(String, f64)> = Vec::new();
-    collect_series_points(v, &mut points);
+    let mut points = series_points(v);
     points.sort_by(|a, b| a.0.cmp(&b.0)); // asc by time (oldest -> newest)
 
     if points.is_empty() {
-        let empty = Paragraph::new("No time-series points in response.")
+        // An empty array is what the client returns for "no data"; anything else is a shape we can't read.
+        let message = if v.as_array().is_some_and(|a| a.is_empty()) {
+            "No data in this range."
+        } else {
+            "No time-series points found (unrecognized response structure)."
+        };
+        let empty = Paragraph::new(message)
             .block(
                 Block::default()
                     .title(" Metric chart ")
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Cyan)),
+                    .border_style(theme.fg(theme.accent)),
             )
-            .style(Style::default().fg(Color::White));
+            .style(theme.fg(theme.text));
         f.render_widget(empty, content_area);
         return;
     }
 
+    if line_chart && content_area.width >= LINE_CHART_MIN_WIDTH {
+        render_metric_line_chart(f, content_area, &points, time_display, metric_type, theme);
+        return;
+    }
+
     let vertical = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(6), Constraint::Length(2)])
@@ -381,17 +484,17 @@ fn render_metric_chart(
         .iter()
         .map(|(ts, val)| {
             let scaled = ((*val / max_v) * 100.0).round().clamp(0.0, 100.0) as u64;
-            Bar::with_label(compact_time_label(ts, use_utc), scaled)
-                .style(Color::Cyan)
-                .value_style((Color::Black, Color::Cyan))
+            Bar::with_label(compact_time_label(ts, time_display), scaled)
+                .style(theme.fg(theme.accent))
+                .value_style(theme.on_accent())
                 .text_value(format!("{:.1}", val))
         })
         .collect();
 
     let title = if let Some(mt) = metric_type {
-        format!(" {} chart ", mt)
+        format!(" {} chart (g: line) ", mt)
     } else {
-        " Metric chart ".to_string()
+        " Metric chart (g: line) ".to_string()
     };
     let chart = BarChart::vertical(bars)
         .bar_width(bar_width)
@@ -401,7 +504,7 @@ fn render_metric_chart(
             Block::default()
                 .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(theme.fg(theme.accent)),
         );
     f.render_widget(chart, chart_area);
 
@@ -424,34 +527,107 @@ fn render_metric_chart(
     let meta_widget = Paragraph::new(meta).block(
         Block::default()
             .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(theme.fg(theme.accent)),
     );
     f.render_widget(meta_widget, meta_area);
 }
 
-/// Extract a sortable time string from a Value (ISO 8601 or similar). Tries common field names.
-fn time_sort_key(v: &Value) -> String {
-    v.get("last_seen")
-        .or_else(|| v.get("first_seen"))
-        .or_else(|| v.get("timestamp"))
-        .or_else(|| v.get("created_at"))
-        .or_else(|| v.get("time"))
-        .or_else(|| v.get("reported_at"))
-        .and_then(|t| t.as_str())
-        .unwrap_or("")
-        .to_string()
+/// Auto-scaled line chart of all `points` (ascending by time), with the newest points as a
+/// time/value table below it.
+fn render_metric_line_chart(
+    f: &mut Frame,
+    content_area: Rect,
+    points: &[(String, f64)],
+    time_display: TimeDisplay,
+    metric_type: Option<&str>,
+    theme: Theme,
+) {
+    let table_rows = points.len().min(LINE_CHART_TABLE_ROWS);
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(6),
+            Constraint::Length(table_rows as u16 + 2),
+        ])
+        .split(content_area);
+
+    let data: Vec<(f64, f64)> = points
+        .iter()
+        .enumerate()
+        .map(|(i, (_, v))| (i as f64, *v))
+        .collect();
+    let min_v = points.iter().map(|(_, v)| *v).fold(f64::MAX, f64::min);
+    let max_v = points.iter().map(|(_, v)| *v).fold(f64::MIN, f64::max);
+    // Pad a flat series so the line sits mid-chart instead of on the axis.
+    let (lo, hi) = if max_v > min_v {
+        (min_v, max_v)
+    } else {
+        (min_v - 1.0, max_v + 1.0)
+    };
+    let unit = metric_type.map(metric_unit).unwrap_or("");
+    let title = match metric_type {
+        Some(mt) => format!(" {} chart (g: bars) ", mt),
+        None => " Metric chart (g: bars) ".to_string(),
+    };
+    let first_ts = compact_time_label(&points[0].0, time_display);
+    let last_ts = compact_time_label(&points[points.len() - 1].0, time_display);
+    let dataset = Dataset::default()
+        .marker(Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(theme.fg(theme.accent))
+        .data(&data);
+    let chart = Chart::new(vec![dataset])
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(theme.fg(theme.accent)),
+        )
+        .x_axis(
+            Axis::default()
+                .bounds([0.0, (points.len().max(2) - 1) as f64])
+                .labels(vec![first_ts, last_ts]),
+        )
+        .y_axis(Axis::default().bounds([lo, hi]).labels(vec![
+            format!("{:.1}", lo),
+            format!("{:.1}", (lo + hi) / 2.0),
+            format!("{:.1}", hi),
+        ]));
+    f.render_widget(chart, vertical[0]);
+
+    let lines: Vec<Line> = points
+        .iter()
+        .rev()
+        .take(table_rows)
+        .map(|(ts, v)| {
+            Line::from(format!(
+                " {}  {:>12.2} {}",
+                format_timestamp_as(ts, time_display),
+                v,
+                unit
+            ))
+        })
+        .collect();
+    let table = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(
+                " Latest {} of {} points — min {:.2}  max {:.2} ",
+                table_rows,
+                points.len(),
+                min_v,
+                max_v
+            ))
+            .borders(Borders::ALL)
+            .border_style(theme.fg(theme.accent)),
+    );
+    f.render_widget(table, vertical[1]);
 }
 
-/// Extract endpoint list from API response (results may be object with "endpoints" or array). Sorted by time desc (latest on top).
+/// Extract endpoint list from API response (see [`extract_list`]). Sorted by time desc (latest on top).
 fn endpoints_as_list(v: &Value) -> Vec<(String, Value)> {
-    let arr = v
-        .get("endpoints")
-        .and_then(|a| a.as_array())
+    let mut out: Vec<(String, Value)> = extract_list(v, "endpoints")
+        .iter()
         .cloned()
-        .or_else(|| v.as_array().cloned())
-        .unwrap_or_default();
-    let mut out: Vec<(String, Value)> = arr
-        .into_iter()
         .map(|o| {
             let name = o
                 .get("name")
@@ -462,7 +638,7 @@ fn endpoints_as_list(v: &Value) -> Vec<(String, Value)> {
             (name, o)
         })
         .collect();
-    out.sort_by(|a, b| time_sort_key(&b.1).cmp(&time_sort_key(&a.1)));
+    out.sort_by_key(|b| std::cmp::Reverse(output::time_sort_key(&b.1)));
     out
 }
 
@@ -497,32 +673,198 @@ fn insights_as_list(v: &Value) -> Vec<(String, Value)> {
             }
         }
     }
-    out.sort_by(|a, b| time_sort_key(&b.1).cmp(&time_sort_key(&a.1)));
+    out.sort_by_key(|b| std::cmp::Reverse(output::time_sort_key(&b.1)));
+    out
+}
+
+/// Extract trace list from API response (results object with "traces" or array). Sorted by time desc (latest on top).
+fn traces_as_list(v: &Value) -> Vec<Value> {
+    let mut out = v
+        .get("traces")
+        .and_then(|a| a.as_array())
+        .cloned()
+        .or_else(|| v.as_array().cloned())
+        .unwrap_or_default();
+    out.sort_by_key(|b| std::cmp::Reverse(output::time_sort_key(b)));
     out
 }
 
+/// One-line trace summary for the Traces list: time, duration, and URI or metric name.
+fn trace_label(v: &Value, time_display: TimeDisplay) -> String {
+    let time = v
+        .get("time")
+        .and_then(|t| t.as_str())
+        .map(|t| format_timestamp_as(t, time_display))
+        .unwrap_or_else(|| "?".to_string());
+    let duration = v
+        .get("total_call_time")
+        .and_then(|d| d.as_f64())
+        .map(|d| format!("{:.0} ms", d))
+        .unwrap_or_else(|| "-".to_string());
+    let what = v
+        .get("uri")
+        .or_else(|| v.get("metric_name"))
+        .and_then(|u| u.as_str())
+        .unwrap_or("?");
+    format!("{}  {:>9}  {}", time, duration, what)
+}
+
+/// Width of the response time / throughput columns of an Endpoints row.
+const ENDPOINT_METRIC_COL: usize = 10;
+
+/// Width of the error badge column of an Endpoints row (shown once the Errors tab has loaded).
+const ENDPOINT_BADGE_COL: usize = 7;
+
+/// Endpoint an error group belongs to (its transaction name), when the group says.
+fn error_group_endpoint(v: &Value) -> Option<&str> {
+    [
+        "endpoint_name",
+        "transaction_name",
+        "metric_name",
+        "endpoint",
+    ]
+    .iter()
+    .find_map(|k| v.get(*k).and_then(|n| n.as_str()))
+}
+
+/// Recent errors per endpoint (lowercased name): the groups' `errors_count`, 1 per group without one.
+fn errors_by_endpoint(errors: &[Value]) -> HashMap<String, u64> {
+    let mut counts = HashMap::new();
+    for group in errors {
+        if let Some(endpoint) = error_group_endpoint(group) {
+            let n = group
+                .get("errors_count")
+                .and_then(|c| c.as_u64())
+                .unwrap_or(1);
+            *counts.entry(endpoint.to_lowercase()).or_insert(0) += n;
+        }
+    }
+    counts
+}
+
+/// Endpoints row: the name (truncated to fit `width`), then response time and throughput right-aligned
+/// in fixed columns (`-` when the endpoint has no such field), then an error badge when `errors` (the
+/// endpoint's recent error count; `None` while errors are not loaded) is non-zero.
+fn endpoint_line(
+    name: &str,
+    v: &Value,
+    width: usize,
+    errors: Option<u64>,
+    theme: Theme,
+) -> Line<'static> {
+    let metric = |key: &str, unit: &str, precision: usize| {
+        let n = v.get(key).and_then(|x| {
+            x.as_f64()
+                .or_else(|| x.as_str().and_then(|s| s.trim().parse().ok()))
+        });
+        let text = n.map_or("-".to_string(), |n| format!("{:.*} {}", precision, n, unit));
+        Span::styled(
+            format!("{:>w$}", text, w = ENDPOINT_METRIC_COL),
+            theme.fg(if n.is_some() {
+                theme.highlight
+            } else {
+                theme.muted
+            }),
+        )
+    };
+    let badge_width = if errors.is_some() {
+        ENDPOINT_BADGE_COL
+    } else {
+        0
+    };
+    let name_width = width
+        .saturating_sub(2 * (ENDPOINT_METRIC_COL + 2) + badge_width)
+        .max(10);
+    let name = output::truncate(name, name_width);
+    let pad = name_width.saturating_sub(name.chars().count());
+    let mut spans = vec![
+        Span::raw(format!("{}{}  ", name, " ".repeat(pad))),
+        metric("response_time", "ms", 0),
+        Span::raw("  "),
+        metric("throughput", "rpm", 1),
+    ];
+    if let Some(n) = errors {
+        let badge = if n > 0 {
+            format!("⚠ {}", n)
+        } else {
+            String::new()
+        };
+        spans.push(Span::styled(
+            format!("{:>w$}", badge, w = badge_width),
+            theme.fg(theme.error).add_modifier(Modifier::BOLD),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Back out one drill level: restore the parent level if any, otherwise leave the drill view.
+fn drill_back(
+    drill: &mut Option<DrillContent>,
+    drill_label: &mut Option<String>,
+    drill_stack: &mut Vec<(String, DrillContent)>,
+    pending_drill_load: &mut Option<PendingDrillLoad>,
+) {
+    if let Some((_, _, h)) = pending_drill_load.take() {
+        h.abort();
+    }
+    match drill_stack.pop() {
+        Some((label, parent)) => {
+            *drill = Some(parent);
+            *drill_label = Some(label);
+        }
+        None => {
+            *drill = None;
+            *drill_label = None;
+        }
+    }
+}
+
+/// One-line error occurrence summary: time and message.
+fn error_label(v: &Value, time_display: TimeDisplay) -> String {
+    let time = v
+        .get("created_at")
+        .and_then(|t| t.as_str())
+        .map(|t| format_timestamp_as(t, time_display))
+        .unwrap_or_else(|| "?".to_string());
+    let message = v
+        .get("message")
+        .and_then(|m| m.as_str())
+        .unwrap_or("?")
+        .replace('\n', " ");
+    format!("{}  {}", time, message)
+}
+
 pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
     let apps: Vec<Value> = client.list_apps(None).await.map_err(|e| e.to_string())?;
+    // If no --app, we need to show app picker first. Otherwise resolve app and go to app view.
+    let mut current_app: Option<(u64, String)> = opts
+        .app
+        .as_ref()
+        .and_then(|a| resolve_app(&apps, a))
+        .map(|i| app_identity(&apps[i]))
+        .transpose()?;
 
+    // Fetches run as tasks on the caller's runtime; each task owns a clone (the client is cheap to clone).
     let client = client.clone();
     enable_raw_mode().map_err(|e| e.to_string())?;
     execute!(io::stdout(), EnterAlternateScreen, Hide).map_err(|e| e.to_string())?;
     let _guard = TerminalGuard;
-    let mut terminal =
-        Terminal::new(ratatui::backend::CrosstermBackend::new(io::stdout())).map_err(|e| e.to_string())?;
+    let mut terminal = Terminal::new(ratatui::backend::CrosstermBackend::new(io::stdout()))
+        .map_err(|e| e.to_string())?;
 
-    // If no --app, we need to show app picker first. Otherwise resolve app and go to app view.
-    let mut current_app: Option<(u64, String)> = opts
-        .app
-        .as_ref()
-        .and_then(|a| resolve_app(&apps, a).map(|(_, id, name)| (id, name)));
     let app_list = apps;
-    let mut app_selected = opts
+    // Without --app, start the picker on the app opened last time (if it still exists).
+    let app_arg = opts
         .app
+        .clone()
+        .or_else(|| state::load().last_app.map(|id| id.to_string()));
+    let mut app_selected = app_arg
         .as_ref()
         .and_then(|a| resolve_app(&app_list, a))
-        .map(|(i, _, _)| i)
         .unwrap_or(0);
+    if let Some((app_id, _)) = current_app {
+        state::save_last_app(app_id);
+    }
 
     let mut tab = opts.tab;
     let mut breadcrumb: Vec<String> = current_app
@@ -533,14 +875,27 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
     let mut selected = 0usize;
     let mut drill: Option<DrillContent> = None; // detail view content (metric series formatted at draw time with width)
     let mut drill_label: Option<String> = None; // extra breadcrumb segment
+    let mut drill_stack: Vec<(String, DrillContent)> = Vec::new(); // parent drill levels (Esc pops one)
     let mut loaded_tabs: HashSet<(u64, Tab)> = HashSet::new(); // skip reload when switching back to a tab
     let mut tab_errors: HashMap<(u64, Tab), String> = HashMap::new();
     let mut pending_tab_loads: HashMap<(u64, Tab), JoinHandle<Result<TabPayload, String>>> =
         HashMap::new();
-    let mut pending_metric_load: Option<(u64, String, JoinHandle<Result<Value, String>>)> = None;
+    let mut pending_drill_load: Option<PendingDrillLoad> = None;
     let refresh_secs = opts.refresh_secs;
     let mut last_refresh = Instant::now();
     let spinner_started = Instant::now();
+    let mut detail_scroll = DetailScroll::default();
+    let mut app_viewport = ListViewport::default(); // app picker scroll position
+    let mut tab_viewport = ListViewport::default(); // list tabs scroll position
+    let mut drill_list_state = ListState::default(); // error group's error list; kept so scrolling follows the cursor
+    let mut flash: Option<(String, Instant)> = None; // transient status shown in the pane title
+    let mut picker_error: Option<String> = None; // app picker: the chosen app cannot be opened (until next key)
+    let mut clipboard: Option<arboard::Clipboard> = None; // opened on first `y`
+    let mut show_help = false; // `?` overlay listing keybindings
+    let mut line_chart = false; // `g` switches metric drill-downs between bar and line chart
+    let mut raw_json = false; // `J` shows drilled-into records as pretty-printed JSON instead of a table
+    let mut filter_editing = false; // `/` prompt open: keys edit the active tab's filter
+    let mut detail_view_key: Option<(Tab, Vec<String>)> = None; // reset scroll when the shown detail changes
     let poll_timeout = std::time::Duration::from_millis(100);
     let search_debounce = std::time::Duration::from_millis(200);
     let mut app_search_pending = String::new();
@@ -549,7 +904,7 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
 
     // If we have an app from --app, start loading initial tab in background.
     if let Some((app_id, _)) = current_app {
-        start_tab_load(&mut pending_tab_loads, &client, app_id, tab);
+        start_tab_load(&mut pending_tab_loads, &client, app_id, tab, &tab_data);
     }
 
     loop {
@@ -562,8 +917,7 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
             if let Some(handle) = pending_tab_loads.remove(&key) {
                 match handle.await {
                     Ok(Ok(payload)) => {
-                        let is_current_app =
-                            current_app.as_ref().map(|(id, _)| *id) == Some(key.0);
+                        let is_current_app = current_app.as_ref().map(|(id, _)| *id) == Some(key.0);
                         if is_current_app {
                             apply_tab_payload(&mut tab_data, key.1, payload);
                             loaded_tabs.insert(key);
@@ -587,15 +941,15 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
             }
         }
 
-        // Apply completed metric drill load.
-        if pending_metric_load
+        // Apply completed drill load (metric series, trace, or error group errors).
+        if pending_drill_load
             .as_ref()
             .map(|(_, _, h)| h.is_finished())
             .unwrap_or(false)
         {
-            if let Some((_, metric_name, handle)) = pending_metric_load.take() {
+            if let Some((_, label, handle)) = pending_drill_load.take() {
                 match handle.await {
-                    Ok(Ok(v)) => drill = Some(DrillContent::MetricSeries(v)),
+                    Ok(Ok(content)) => drill = Some(content),
                     Ok(Err(e)) => {
                         drill = Some(DrillContent::Preformatted(format!("Error: {}", e)));
                     }
@@ -604,7 +958,7 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                     }
                 }
                 if drill_label.is_none() {
-                    drill_label = Some(metric_name);
+                    drill_label = Some(label);
                 }
             }
         }
@@ -622,9 +976,9 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
         }
 
         let loading_msg = if let Some((app_id, _)) = current_app {
-            if let Some((metric_app_id, metric_name, _)) = pending_metric_load.as_ref() {
-                if *metric_app_id == app_id {
-                    Some(format!("Loading metric {}…", metric_name))
+            if let Some((drill_app_id, label, _)) = pending_drill_load.as_ref() {
+                if *drill_app_id == app_id {
+                    Some(format!("Loading {}…", label))
                 } else {
                     None
                 }
@@ -634,25 +988,27 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
             {
                 Some(format!("Loading {}…", tab.as_str().to_lowercase()))
             } else if tab_data.list_len(tab) == 0 && drill.is_none() {
-                tab_errors.get(&(app_id, tab)).map(|e| format!("Error: {}", e))
+                tab_errors
+                    .get(&(app_id, tab))
+                    .map(|e| format!("Error: {}", e))
             } else {
                 None
             }
         } else {
-            None
+            picker_error.as_ref().map(|e| format!("Error: {}", e))
         };
 
         // Safety net: if current tab was not kicked off by a keypath, start it here.
         if let Some((app_id, _)) = current_app {
             if !loaded_tabs.contains(&(app_id, tab))
                 && !pending_tab_loads.contains_key(&(app_id, tab))
-                && pending_metric_load.is_none()
+                && pending_drill_load.is_none()
             {
-                start_tab_load(&mut pending_tab_loads, &client, app_id, tab);
+                start_tab_load(&mut pending_tab_loads, &client, app_id, tab, &tab_data);
             }
         }
 
-        let pending_count = pending_tab_loads.len() + usize::from(pending_metric_load.is_some());
+        let pending_count = pending_tab_loads.len() + usize::from(pending_drill_load.is_some());
         let loading_indicator = if pending_count > 0 {
             let frames = ["◐", "◓", "◑", "◒"];
             let idx =
@@ -662,6 +1018,33 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
             None
         };
 
+        let time_display = opts.time_display;
+        let network = format!(
+            "Requests: {}s timeout, {} (--timeout, --retries)",
+            client.timeout().as_secs(),
+            match client.retries() {
+                0 => "no retries".to_string(),
+                1 => "1 retry".to_string(),
+                n => format!("{} retries", n),
+            }
+        );
+        let drill_path: Vec<String> = drill_stack
+            .iter()
+            .map(|(l, _)| l.clone())
+            .chain(drill_label.clone())
+            .collect();
+        let view_key = Some((tab, drill_path.clone()));
+        if view_key != detail_view_key {
+            detail_scroll.offset = 0;
+            detail_view_key = view_key;
+        }
+        let term_height = terminal.size().map_or(24, |s| s.height);
+        let viewport = if current_app.is_some() {
+            &mut tab_viewport
+        } else {
+            &mut app_viewport
+        };
+        viewport.rows = list_rows(term_height, current_app.is_some());
         let (bc, tab_names, list_items, content_title, detail_text) = build_ui_state(
             current_app.as_ref(),
             &breadcrumb,
@@ -671,12 +1054,38 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
             app_search_committed.as_str(),
             app_selected,
             selected,
-            drill_label.as_deref(),
+            viewport,
+            &drill_path,
             loading_msg.as_deref(),
             drill.is_some(),
+            filter_editing,
+            time_display,
+            // Inside the content pane's borders.
+            terminal
+                .size()
+                .map_or(80, |s| usize::from(s.width.saturating_sub(2))),
+            current_app
+                .as_ref()
+                .is_some_and(|(app_id, _)| loaded_tabs.contains(&(*app_id, Tab::Errors))),
+            opts.theme,
         );
+        if flash
+            .as_ref()
+            .is_some_and(|(_, at)| at.elapsed() >= FLASH_DURATION)
+        {
+            flash = None;
+        }
+        let content_title = match &flash {
+            Some((msg, _)) => format!("{}— {} ", content_title, msg),
+            None => content_title,
+        };
+        // A failed load of the current tab (e.g. a refresh that hit a transient error) keeps whatever
+        // was shown before; the breadcrumb says so until a load succeeds.
+        let status_error = current_app
+            .as_ref()
+            .and_then(|(app_id, _)| tab_errors.get(&(*app_id, tab)))
+            .map(|e| format!("✗ {} — press r to retry", e));
 
-        let use_utc = opts.use_utc;
         terminal
             .draw(|f| {
                 draw_ui(
@@ -686,35 +1095,160 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                     &tab_names,
                     list_items,
                     if current_app.is_none() {
-                        Some(app_selected)
+                        Some(app_viewport.relative(app_selected))
                     } else {
-                        Some(selected)
+                        Some(tab_viewport.relative(selected))
                     },
+                    &mut drill_list_state,
                     loading_indicator.as_deref(),
+                    status_error.as_deref(),
                     content_title,
                     detail_text.as_deref(),
                     drill.as_ref(),
+                    &mut detail_scroll,
                     refresh_secs,
-                    use_utc,
+                    time_display,
+                    line_chart,
+                    raw_json,
+                    opts.theme,
                 );
+                if show_help {
+                    draw_help(f, opts.theme, &network);
+                }
             })
             .map_err(|e| e.to_string())?;
 
         let should_refresh = refresh_secs > 0
             && current_app.is_some()
             && last_refresh.elapsed() >= std::time::Duration::from_secs(refresh_secs);
+        // Checked before input so steady key presses cannot postpone it. The load runs as a spawned task
+        // keyed by (app_id, tab); a tick that arrives while it is still in flight is coalesced into it.
+        if should_refresh {
+            last_refresh = Instant::now();
+            if let Some((app_id, _)) = current_app {
+                start_tab_load(&mut pending_tab_loads, &client, app_id, tab, &tab_data);
+            }
+        }
 
         if event::poll(poll_timeout).map_err(|e| e.to_string())? {
             if let Event::Key(k) = event::read().map_err(|e| e.to_string())? {
+                if show_help {
+                    // Help overlay swallows keys so the view underneath stays as it was.
+                    if matches!(k.code, KeyCode::Char('?') | KeyCode::Esc) {
+                        show_help = false;
+                    }
+                    continue;
+                }
+                if filter_editing {
+                    let filter = tab_data.filters.entry(tab).or_default();
+                    match k.code {
+                        KeyCode::Char(c) => filter.push(c),
+                        KeyCode::Backspace => {
+                            filter.pop();
+                        }
+                        KeyCode::Enter => filter_editing = false,
+                        KeyCode::Esc => {
+                            filter.clear();
+                            filter_editing = false;
+                        }
+                        _ => {}
+                    }
+                    if tab_data.filters.get(&tab).is_some_and(|q| q.is_empty()) {
+                        tab_data.filters.remove(&tab);
+                    }
+                    selected = 0;
+                    continue;
+                }
+                picker_error = None;
                 match k.code {
                     KeyCode::Char('q') => break,
+                    KeyCode::Char('?') => show_help = true,
+                    KeyCode::Char('g') if matches!(drill, Some(DrillContent::MetricSeries(_))) => {
+                        line_chart = !line_chart;
+                    }
+                    KeyCode::Char('J') if matches!(drill, Some(DrillContent::Record { .. })) => {
+                        raw_json = !raw_json;
+                        detail_scroll.offset = 0;
+                    }
+                    KeyCode::Char('y') if current_app.is_some() => {
+                        let msg =
+                            yank_selected(&mut clipboard, tab, &tab_data, selected, drill.as_ref());
+                        flash = Some((msg, Instant::now()));
+                    }
+                    KeyCode::Char('o') if current_app.is_some() => {
+                        if let Some((app_id, _)) = current_app {
+                            let msg = match selected_web_url(app_id, tab, &tab_data, selected) {
+                                Ok(url) => match open::that_detached(&url) {
+                                    Ok(()) => format!("opened {}", url),
+                                    Err(e) => format!("cannot open browser ({}): {}", e, url),
+                                },
+                                Err(e) => format!("no web page: {}", e),
+                            };
+                            flash = Some((msg, Instant::now()));
+                        }
+                    }
+                    KeyCode::Char('e') if current_app.is_some() => {
+                        if let Some((app_id, _)) = current_app {
+                            let msg = match export_view(
+                                app_id,
+                                tab,
+                                &tab_data,
+                                drill.as_ref(),
+                                drill_label.as_deref(),
+                            ) {
+                                Ok(path) => format!("exported to {}", path.display()),
+                                Err(e) => format!("export failed: {}", e),
+                            };
+                            flash = Some((msg, Instant::now()));
+                        }
+                    }
+                    KeyCode::Char('r') if current_app.is_some() => {
+                        // Force a reload of the current tab (also retries a failed one); a load already in
+                        // flight is left to finish.
+                        if let Some((app_id, _)) = current_app {
+                            if !pending_tab_loads.contains_key(&(app_id, tab)) {
+                                loaded_tabs.remove(&(app_id, tab));
+                                start_tab_load(
+                                    &mut pending_tab_loads,
+                                    &client,
+                                    app_id,
+                                    tab,
+                                    &tab_data,
+                                );
+                                flash = Some(("Refreshing…".to_string(), Instant::now()));
+                            }
+                        }
+                    }
+                    KeyCode::Char('/') if current_app.is_some() && drill.is_none() => {
+                        filter_editing = true;
+                        selected = 0;
+                    }
+                    KeyCode::Char('s') if current_app.is_some() && drill.is_none() => {
+                        let sort = tab_data.sorts.entry(tab).or_default();
+                        sort.field = (sort.field + 1) % sort_fields(tab).len();
+                        sort.ascending = false;
+                        selected = 0;
+                    }
+                    KeyCode::Char('S') if current_app.is_some() && drill.is_none() => {
+                        let sort = tab_data.sorts.entry(tab).or_default();
+                        sort.ascending = !sort.ascending;
+                        selected = 0;
+                    }
+                    KeyCode::Esc if drill.is_none() && tab_data.filters.remove(&tab).is_some() => {
+                        // First Esc clears the active tab's filter.
+                        selected = 0;
+                    }
                     KeyCode::Esc => {
                         if drill.is_some() {
-                            drill = None;
-                            drill_label = None;
+                            drill_back(
+                                &mut drill,
+                                &mut drill_label,
+                                &mut drill_stack,
+                                &mut pending_drill_load,
+                            );
                         } else if current_app.is_some() {
                             // Back to project list
-                            if let Some((_, _, h)) = pending_metric_load.take() {
+                            if let Some((_, _, h)) = pending_drill_load.take() {
                                 h.abort();
                             }
                             for (_, h) in pending_tab_loads.drain() {
@@ -734,8 +1268,12 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                     }
                     KeyCode::Left | KeyCode::Char('h') => {
                         if drill.is_some() {
-                            drill = None;
-                            drill_label = None;
+                            drill_back(
+                                &mut drill,
+                                &mut drill_label,
+                                &mut drill_stack,
+                                &mut pending_drill_load,
+                            );
                         } else if current_app.is_some() {
                             let tabs = Tab::all();
                             let i = tabs.iter().position(|&t| t == tab).unwrap_or(0);
@@ -745,7 +1283,13 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                                 if !loaded_tabs.contains(&(app_id, tab))
                                     && !pending_tab_loads.contains_key(&(app_id, tab))
                                 {
-                                    start_tab_load(&mut pending_tab_loads, &client, app_id, tab);
+                                    start_tab_load(
+                                        &mut pending_tab_loads,
+                                        &client,
+                                        app_id,
+                                        tab,
+                                        &tab_data,
+                                    );
                                 }
                             }
                             selected = 0;
@@ -753,8 +1297,12 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                     }
                     KeyCode::Right | KeyCode::Char('l') => {
                         if drill.is_some() {
-                            drill = None;
-                            drill_label = None;
+                            drill_back(
+                                &mut drill,
+                                &mut drill_label,
+                                &mut drill_stack,
+                                &mut pending_drill_load,
+                            );
                         } else if current_app.is_some() {
                             let tabs = Tab::all();
                             let i = tabs.iter().position(|&t| t == tab).unwrap_or(0);
@@ -764,56 +1312,88 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                                 if !loaded_tabs.contains(&(app_id, tab))
                                     && !pending_tab_loads.contains_key(&(app_id, tab))
                                 {
-                                    start_tab_load(&mut pending_tab_loads, &client, app_id, tab);
+                                    start_tab_load(
+                                        &mut pending_tab_loads,
+                                        &client,
+                                        app_id,
+                                        tab,
+                                        &tab_data,
+                                    );
                                 }
                             }
                             selected = 0;
                         }
                     }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        if drill.is_none() {
-                            if current_app.is_none() {
-                                let max = filtered_app_indices(&app_list, &app_search_committed)
-                                    .len()
-                                    .saturating_sub(1);
-                                app_selected = app_selected.saturating_sub(1).min(max);
-                            } else {
-                                let max = tab_data.list_len(tab).saturating_sub(1);
-                                selected = selected.saturating_sub(1).min(max);
-                            }
+                    KeyCode::Up | KeyCode::Char('k')
+                        if matches!(drill, Some(DrillContent::ErrorList { .. })) =>
+                    {
+                        if let Some(DrillContent::ErrorList { selected, .. }) = drill.as_mut() {
+                            *selected = selected.saturating_sub(1);
                         }
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        if drill.is_none() {
-                            if current_app.is_none() {
-                                let max = filtered_app_indices(&app_list, &app_search_committed)
-                                    .len()
-                                    .saturating_sub(1);
-                                app_selected = (app_selected + 1).min(max);
-                            } else {
-                                let max = tab_data.list_len(tab).saturating_sub(1);
-                                selected = (selected + 1).min(max);
-                            }
+                    KeyCode::Down | KeyCode::Char('j')
+                        if matches!(drill, Some(DrillContent::ErrorList { .. })) =>
+                    {
+                        if let Some(DrillContent::ErrorList { errors, selected }) = drill.as_mut() {
+                            *selected = (*selected + 1).min(errors.len().saturating_sub(1));
+                        }
+                    }
+                    KeyCode::Up | KeyCode::Char('k') if drill.is_some() => {
+                        detail_scroll.offset = detail_scroll.offset.saturating_sub(1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if drill.is_some() => {
+                        detail_scroll.offset = detail_scroll.offset.saturating_add(1);
+                        // clamped at draw
+                    }
+                    KeyCode::PageUp => {
+                        detail_scroll.offset = detail_scroll
+                            .offset
+                            .saturating_sub(detail_scroll.viewport.max(1));
+                    }
+                    KeyCode::PageDown => {
+                        detail_scroll.offset = detail_scroll
+                            .offset
+                            .saturating_add(detail_scroll.viewport.max(1));
+                    }
+                    KeyCode::Up | KeyCode::Char('k') if drill.is_none() => {
+                        if current_app.is_none() {
+                            let max = filtered_app_indices(&app_list, &app_search_committed)
+                                .len()
+                                .saturating_sub(1);
+                            app_selected = app_selected.saturating_sub(1).min(max);
+                        } else {
+                            let max = tab_data.list_len(tab).saturating_sub(1);
+                            selected = selected.saturating_sub(1).min(max);
+                        }
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if drill.is_none() => {
+                        if current_app.is_none() {
+                            let max = filtered_app_indices(&app_list, &app_search_committed)
+                                .len()
+                                .saturating_sub(1);
+                            app_selected = (app_selected + 1).min(max);
+                        } else {
+                            let max = tab_data.list_len(tab).saturating_sub(1);
+                            selected = (selected + 1).min(max);
                         }
                     }
                     KeyCode::Enter => {
                         if current_app.is_none() {
                             let indices = filtered_app_indices(&app_list, &app_search_committed);
-                            if let Some(&idx) = indices.get(app_selected) {
-                                let app = &app_list[idx];
-                                let app_id = app.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
-                                let name = app
-                                    .get("name")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or("?")
-                                    .to_string();
+                            let identity = indices
+                                .get(app_selected)
+                                .map(|&idx| app_identity(&app_list[idx]));
+                            if let Some(Err(e)) = identity {
+                                picker_error = Some(e);
+                            } else if let Some(Ok((app_id, name))) = identity {
                                 current_app = Some((app_id, name.clone()));
+                                state::save_last_app(app_id);
                                 breadcrumb = vec![name];
                                 tab = Tab::Endpoints;
                                 tab_data = TabData::default();
                                 loaded_tabs.clear();
                                 tab_errors.clear();
-                                if let Some((_, _, h)) = pending_metric_load.take() {
+                                if let Some((_, _, h)) = pending_drill_load.take() {
                                     h.abort();
                                 }
                                 for (_, h) in pending_tab_loads.drain() {
@@ -823,18 +1403,77 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                                     if !loaded_tabs.contains(&(id, tab))
                                         && !pending_tab_loads.contains_key(&(id, tab))
                                     {
-                                        start_tab_load(&mut pending_tab_loads, &client, id, tab);
+                                        start_tab_load(
+                                            &mut pending_tab_loads,
+                                            &client,
+                                            id,
+                                            tab,
+                                            &tab_data,
+                                        );
                                     }
                                 }
                                 selected = 0;
                             }
+                        } else if let Some(DrillContent::ErrorList { errors, selected }) = &drill {
+                            // Third level: one error occurrence.
+                            if let Some(err) = errors.get(*selected) {
+                                let label = err
+                                    .get("id")
+                                    .and_then(|v| v.as_u64())
+                                    .map(|id| format!("#{}", id))
+                                    .unwrap_or_else(|| format!("#{}", selected + 1));
+                                let record = DrillContent::Record {
+                                    value: err.clone(),
+                                    text: format_endpoint_table(err),
+                                };
+                                if let Some(parent) = drill.take() {
+                                    drill_stack
+                                        .push((drill_label.take().unwrap_or_default(), parent));
+                                }
+                                drill_label = Some(label);
+                                drill = Some(record);
+                            }
                         } else if drill.is_some() {
-                            drill = None;
-                            drill_label = None;
+                            let group_id = tab_data.error_group_id;
+                            match (current_app.as_ref(), group_id) {
+                                (Some(&(app_id, _)), Some(error_id))
+                                    if tab == Tab::Errors && drill_stack.is_empty() =>
+                                {
+                                    // Second level: individual errors of the selected group.
+                                    if let Some((_, _, old_handle)) = pending_drill_load.take() {
+                                        old_handle.abort();
+                                    }
+                                    if let Some(parent) = drill.take() {
+                                        drill_stack
+                                            .push((drill_label.take().unwrap_or_default(), parent));
+                                    }
+                                    let label = "errors".to_string();
+                                    drill_label = Some(label.clone());
+                                    drill = Some(DrillContent::Preformatted(
+                                        "Loading errors…".to_string(),
+                                    ));
+                                    let client_clone = client.clone();
+                                    let handle = tokio::spawn(async move {
+                                        fetch_error_group_errors(&client_clone, app_id, error_id)
+                                            .await
+                                            .map(|errors| DrillContent::ErrorList {
+                                                errors,
+                                                selected: 0,
+                                            })
+                                    });
+                                    pending_drill_load = Some((app_id, label, handle));
+                                }
+                                _ => drill_back(
+                                    &mut drill,
+                                    &mut drill_label,
+                                    &mut drill_stack,
+                                    &mut pending_drill_load,
+                                ),
+                            }
                         } else if let Some((app_id, _)) = current_app {
                             if tab == Tab::Metrics {
                                 if let Some(mt) = tab_data.get_metric_type(selected) {
-                                    if let Some((_, _, old_handle)) = pending_metric_load.take() {
+                                    if let Some((_, _, old_handle)) = pending_drill_load.take() {
                                         old_handle.abort();
                                     }
                                     let metric_name = mt.to_string();
@@ -848,42 +1487,110 @@ pub async fn run(client: &Client, opts: Options) -> Result<(), String> {
                                     let handle = tokio::spawn(async move {
                                         fetch_metric_series(&client_clone, app_id, &metric_clone)
                                             .await
+                                            .map(DrillContent::MetricSeries)
                                     });
-                                    pending_metric_load = Some((app_id, metric_name, handle));
+                                    pending_drill_load = Some((app_id, metric_name, handle));
+                                }
+                            } else if tab == Tab::Traces {
+                                if let Some((label, trace)) = tab_data.get_item(tab, selected) {
+                                    match trace.get("id").and_then(|v| v.as_u64()) {
+                                        Some(trace_id) => {
+                                            if let Some((_, _, old_handle)) =
+                                                pending_drill_load.take()
+                                            {
+                                                old_handle.abort();
+                                            }
+                                            drill_label = Some(label.clone());
+                                            drill = Some(DrillContent::Preformatted(format!(
+                                                "Loading {}…",
+                                                label
+                                            )));
+                                            let client_clone = client.clone();
+                                            let handle = tokio::spawn(async move {
+                                                fetch_trace(&client_clone, app_id, trace_id)
+                                                    .await
+                                                    .map(|v| DrillContent::Record {
+                                                        text: format_endpoint_table(&v),
+                                                        value: v,
+                                                    })
+                                            });
+                                            pending_drill_load = Some((app_id, label, handle));
+                                        }
+                                        None => {
+                                            drill_label = Some(label);
+                                            drill = Some(DrillContent::Preformatted(
+                                                "Trace has no id; cannot fetch details."
+                                                    .to_string(),
+                                            ));
+                                        }
+                                    }
                                 }
                             } else if let Some((label, detail_value)) =
                                 tab_data.get_item(tab, selected)
                             {
+                                let mut text = format_endpoint_table(&detail_value);
+                                if tab == Tab::Endpoints {
+                                    text.push_str("\n  Press t to list traces for this endpoint.");
+                                }
+                                if tab == Tab::Errors {
+                                    tab_data.error_group_id =
+                                        detail_value.get("id").and_then(|v| v.as_u64());
+                                    if tab_data.error_group_id.is_some() {
+                                        text.push_str(
+                                            "\n  Press Enter to list individual errors (max 100).",
+                                        );
+                                    }
+                                }
                                 drill_label = Some(label.clone());
-                                drill = Some(DrillContent::Preformatted(format_endpoint_table(
-                                    &detail_value,
-                                )));
+                                drill = Some(DrillContent::Record {
+                                    value: detail_value,
+                                    text,
+                                });
                             }
                         }
                     }
-                    KeyCode::Backspace => {
-                        if current_app.is_none() {
-                            app_search_pending.pop();
-                            app_search_last_typed = Some(Instant::now());
+                    KeyCode::Char('t') if current_app.is_some() && tab == Tab::Endpoints => {
+                        // List traces for the selected endpoint (from the list or its drill view).
+                        if let Some((app_id, _)) = current_app {
+                            if let Some((name, endpoint)) = tab_data.get_item(tab, selected) {
+                                tab_data.trace_endpoint =
+                                    endpoint_id_of(&endpoint).map(|id| (id, name));
+                                tab_data.traces.clear();
+                                loaded_tabs.remove(&(app_id, Tab::Traces));
+                                tab_errors.remove(&(app_id, Tab::Traces));
+                                if let Some(h) = pending_tab_loads.remove(&(app_id, Tab::Traces)) {
+                                    h.abort();
+                                }
+                                drill = None;
+                                drill_label = None;
+                                drill_stack.clear();
+                                tab = Tab::Traces;
+                                selected = 0;
+                                start_tab_load(
+                                    &mut pending_tab_loads,
+                                    &client,
+                                    app_id,
+                                    tab,
+                                    &tab_data,
+                                );
+                            }
                         }
                     }
-                    KeyCode::Char(c) => {
-                        // Only add to search when on app list; leave q/h/j/k/l for quit and navigation
-                        if current_app.is_none() && !['q', 'h', 'j', 'k', 'l'].contains(&c) {
-                            app_search_pending.push(c);
-                            app_search_last_typed = Some(Instant::now());
-                        }
+                    KeyCode::Backspace if current_app.is_none() => {
+                        app_search_pending.pop();
+                        app_search_last_typed = Some(Instant::now());
+                    }
+                    // Only add to search when on app list; leave q/h/j/k/l/? for quit, navigation and help
+                    KeyCode::Char(c)
+                        if current_app.is_none()
+                            && !['q', 'h', 'j', 'k', 'l', '?'].contains(&c) =>
+                    {
+                        app_search_pending.push(c);
+                        app_search_last_typed = Some(Instant::now());
                     }
                     _ => {}
                 }
             }
-        } else if should_refresh {
-            last_refresh = Instant::now();
-            if let Some((app_id, _)) = current_app {
-                if !pending_tab_loads.contains_key(&(app_id, tab)) {
-                    start_tab_load(&mut pending_tab_loads, &client, app_id, tab);
-                }
-            }
         }
     }
 
@@ -899,20 +1606,175 @@ struct TabData {
     insights: Vec<(String, Value)>,
     metrics: Vec<String>,
     errors: Vec<Value>,
+    traces: Vec<Value>,
+    /// Endpoint (id, name) whose traces the Traces tab lists; set with `t` on an endpoint.
+    trace_endpoint: Option<(String, String)>,
+    /// Id of the error group last opened in the Errors tab (for listing its individual errors).
+    error_group_id: Option<u64>,
+    /// Per-tab `/` filter query (case-insensitive substring on the row label).
+    filters: HashMap<Tab, String>,
+    /// Per-tab sort key/direction chosen with `s` / `S`.
+    sorts: HashMap<Tab, SortState>,
+}
+
+/// Sort keys `s` cycles through for a tab. The first is the load order (latest first).
+fn sort_fields(tab: Tab) -> &'static [&'static str] {
+    match tab {
+        Tab::Endpoints => &[
+            "time",
+            "response_time",
+            "throughput",
+            "error_rate",
+            "percentage_of_total",
+            "name",
+        ],
+        Tab::Insights => &["time", "name"],
+        Tab::Metrics => &["default", "name"],
+        Tab::Errors => &["time", "errors_count", "last_error_at", "name"],
+        Tab::Traces => &["time", "total_call_time", "mem_delta", "name"],
+    }
+}
+
+/// Active sort of a tab: index into [`sort_fields`] and direction.
+#[derive(Clone, Copy, Default)]
+struct SortState {
+    field: usize,
+    /// Ascending instead of the key's natural order (load order, or largest/last first).
+    ascending: bool,
+}
+
+impl SortState {
+    /// Whether the computed ascending order must be reversed for display.
+    fn reversed(self) -> bool {
+        // Load order is already descending; other keys sort ascending and show largest first.
+        if self.field == 0 {
+            self.ascending
+        } else {
+            !self.ascending
+        }
+    }
+    /// Title suffix such as `sort: response_time ↓`, or None for the default order.
+    fn label(self, tab: Tab) -> Option<String> {
+        let (field, arrow) = self.key(tab)?;
+        Some(format!("sort: {} {}", field, arrow))
+    }
+    /// Breadcrumb form of [`label`](Self::label), e.g. `sort:response_time↓`.
+    fn breadcrumb(self, tab: Tab) -> Option<String> {
+        let (field, arrow) = self.key(tab)?;
+        Some(format!("sort:{}{}", field, arrow))
+    }
+    /// Sort key and arrow, or None for the default order.
+    fn key(self, tab: Tab) -> Option<(&'static str, char)> {
+        if self.field == 0 && !self.ascending {
+            return None;
+        }
+        let arrow = if self.ascending { '↑' } else { '↓' };
+        Some((sort_fields(tab)[self.field], arrow))
+    }
+}
+
+/// Compare two sort values numerically when both are numbers (or numeric strings), else as strings.
+/// Missing values compare as empty strings.
+fn compare_sort_values(a: Option<&Value>, b: Option<&Value>) -> std::cmp::Ordering {
+    fn as_number(v: Option<&Value>) -> Option<f64> {
+        let v = v?;
+        v.as_f64().or_else(|| v.as_str()?.trim().parse().ok())
+    }
+    fn as_text(v: Option<&Value>) -> String {
+        match v {
+            Some(Value::String(s)) => s.to_lowercase(),
+            Some(Value::Null) | None => String::new(),
+            Some(other) => other.to_string(),
+        }
+    }
+    match (as_number(a), as_number(b)) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        _ => as_text(a).cmp(&as_text(b)),
+    }
 }
 
 impl TabData {
-    /// Length of the list for the given tab only (scoped to active tab).
-    fn list_len(&self, tab: Tab) -> usize {
+    /// Unfiltered length of the list for the given tab.
+    fn raw_len(&self, tab: Tab) -> usize {
         match tab {
             Tab::Endpoints => self.endpoints.len(),
             Tab::Insights => self.insights.len(),
             Tab::Metrics => self.metrics.len(),
             Tab::Errors => self.errors.len(),
+            Tab::Traces => self.traces.len(),
+        }
+    }
+    /// Text the `/` filter matches against (the row's display label).
+    fn filter_text(&self, tab: Tab, index: usize) -> String {
+        match tab {
+            Tab::Endpoints => self.endpoints[index].0.clone(),
+            Tab::Insights => self.insights[index].0.clone(),
+            Tab::Metrics => self.metrics[index].clone(),
+            Tab::Errors => error_group_label(&self.errors[index]).to_string(),
+            Tab::Traces => {
+                let v = &self.traces[index];
+                v.get("uri")
+                    .or_else(|| v.get("metric_name"))
+                    .and_then(|u| u.as_str())
+                    .unwrap_or("")
+                    .to_string()
+            }
+        }
+    }
+    /// Field of row `index` used when sorting by `field` ("name" sorts by the row label).
+    fn sort_value(&self, tab: Tab, index: usize, field: &str) -> Option<Value> {
+        if field == "name" {
+            return Some(Value::String(self.filter_text(tab, index)));
+        }
+        let v = match tab {
+            Tab::Endpoints => &self.endpoints[index].1,
+            Tab::Insights => &self.insights[index].1,
+            Tab::Metrics => return None,
+            Tab::Errors => &self.errors[index],
+            Tab::Traces => &self.traces[index],
+        };
+        v.get(field).cloned()
+    }
+    /// Indices (into the tab's list) of rows shown under the tab's active filter, in display order
+    /// (the tab's sort key applied on top of the load order, which is latest first).
+    fn visible_indices(&self, tab: Tab) -> Vec<usize> {
+        let q = normalize_query(self.filters.get(&tab).map(String::as_str).unwrap_or(""));
+        let mut out: Vec<usize> = (0..self.raw_len(tab))
+            .filter(|&i| matches_query(&self.filter_text(tab, i), &q))
+            .collect();
+        let sort = self.sorts.get(&tab).copied().unwrap_or_default();
+        if sort.field > 0 {
+            let field = sort_fields(tab)[sort.field];
+            out.sort_by(|&a, &b| {
+                compare_sort_values(
+                    self.sort_value(tab, a, field).as_ref(),
+                    self.sort_value(tab, b, field).as_ref(),
+                )
+            });
+        }
+        if sort.reversed() {
+            out.reverse();
         }
+        out
+    }
+    /// Rows currently shown in the tab (filtered and sorted) as a JSON array.
+    fn visible_values(&self, tab: Tab) -> Value {
+        let rows = self.visible_indices(tab).into_iter().map(|i| match tab {
+            Tab::Endpoints => self.endpoints[i].1.clone(),
+            Tab::Insights => self.insights[i].1.clone(),
+            Tab::Metrics => Value::String(self.metrics[i].clone()),
+            Tab::Errors => self.errors[i].clone(),
+            Tab::Traces => self.traces[i].clone(),
+        });
+        Value::Array(rows.collect())
+    }
+    /// Length of the visible (filtered) list for the given tab only (scoped to active tab).
+    fn list_len(&self, tab: Tab) -> usize {
+        self.visible_indices(tab).len()
     }
-    /// Item at index for the given tab only. Metrics tab has no Value items (use get_metric_type).
+    /// Item at visible index for the given tab only. Metrics tab has no Value items (use get_metric_type).
     fn get_item(&self, tab: Tab, index: usize) -> Option<(String, Value)> {
+        let index = *self.visible_indices(tab).get(index)?;
         match tab {
             Tab::Endpoints => self.endpoints.get(index).cloned(),
             Tab::Insights => self.insights.get(index).cloned(),
@@ -921,14 +1783,48 @@ impl TabData {
                 .errors
                 .get(index)
                 .map(|v| (format!("Error #{}", index + 1), v.clone())),
+            Tab::Traces => self.traces.get(index).map(|v| {
+                let label = v
+                    .get("id")
+                    .and_then(|id| id.as_u64())
+                    .map(|id| format!("Trace #{}", id))
+                    .unwrap_or_else(|| format!("Trace {}", index + 1));
+                (label, v.clone())
+            }),
         }
     }
     fn get_metric_type(&self, index: usize) -> Option<&str> {
+        let index = *self.visible_indices(Tab::Metrics).get(index)?;
         self.metrics.get(index).map(String::as_str)
     }
 }
 
-async fn fetch_tab_payload(client: &Client, app_id: u64, tab: Tab) -> Result<TabPayload, String> {
+/// Display label for an error group row: its message, falling back to its name.
+fn error_group_label(v: &Value) -> &str {
+    v.get("message")
+        .or_else(|| v.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("?")
+}
+
+/// Errors row: the group label, then its share of all occurrences when known.
+fn error_group_line(v: &Value, theme: Theme) -> Line<'static> {
+    let mut spans = vec![Span::raw(error_group_label(v).to_string())];
+    if let Some(pct) = v.get("share_pct").and_then(|p| p.as_f64()) {
+        spans.push(Span::styled(
+            format!("  {:.1}%", pct),
+            theme.fg(theme.highlight),
+        ));
+    }
+    Line::from(spans)
+}
+
+async fn fetch_tab_payload(
+    client: &Client,
+    app_id: u64,
+    tab: Tab,
+    endpoint_id: Option<String>,
+) -> Result<TabPayload, String> {
     match tab {
         Tab::Endpoints => {
             let v = fetch_endpoints(client, app_id).await?;
@@ -938,12 +1834,22 @@ async fn fetch_tab_payload(client: &Client, app_id: u64, tab: Tab) -> Result<Tab
             let v = fetch_insights(client, app_id).await?;
             Ok(TabPayload::Insights(insights_as_list(&v)))
         }
-        Tab::Metrics => Ok(TabPayload::Metrics(fetch_metrics_list(client, app_id).await?)),
+        Tab::Metrics => Ok(TabPayload::Metrics(
+            fetch_metrics_list(client, app_id).await?,
+        )),
         Tab::Errors => {
             let mut errs = fetch_errors(client, app_id).await?;
-            errs.sort_by_key(|b| std::cmp::Reverse(time_sort_key(b))); // desc (latest first)
+            errs.sort_by_key(|b| std::cmp::Reverse(output::time_sort_key(b))); // desc (latest first)
+            annotate_error_share(&mut errs);
             Ok(TabPayload::Errors(errs))
         }
+        Tab::Traces => match endpoint_id {
+            Some(id) => {
+                let v = fetch_endpoint_traces(client, app_id, &id).await?;
+                Ok(TabPayload::Traces(traces_as_list(&v)))
+            }
+            None => Ok(TabPayload::Traces(Vec::new())),
+        },
     }
 }
 
@@ -953,6 +1859,7 @@ fn apply_tab_payload(data: &mut TabData, tab: Tab, payload: TabPayload) {
         (Tab::Insights, TabPayload::Insights(v)) => data.insights = v,
         (Tab::Metrics, TabPayload::Metrics(v)) => data.metrics = v,
         (Tab::Errors, TabPayload::Errors(v)) => data.errors = v,
+        (Tab::Traces, TabPayload::Traces(v)) => data.traces = v,
         _ => {}
     }
 }
@@ -962,15 +1869,57 @@ fn start_tab_load(
     client: &Client,
     app_id: u64,
     tab: Tab,
+    data: &TabData,
 ) {
+    // Already loading (e.g. a slow fetch outlasting the refresh interval): don't queue another.
     if pending.contains_key(&(app_id, tab)) {
         return;
     }
     let client_clone = client.clone();
-    let handle = tokio::spawn(async move { fetch_tab_payload(&client_clone, app_id, tab).await });
+    let endpoint_id = data.trace_endpoint.as_ref().map(|(id, _)| id.clone());
+    let handle =
+        tokio::spawn(
+            async move { fetch_tab_payload(&client_clone, app_id, tab, endpoint_id).await },
+        );
     pending.insert((app_id, tab), handle);
 }
 
+/// Scroll position of a list pane. Only the rows in view are turned into `ListItem`s each frame, so long
+/// lists (thousands of apps) cost O(visible rows) to draw.
+#[derive(Debug, Default)]
+struct ListViewport {
+    /// Index of the first row in view.
+    offset: usize,
+    /// Rows that fit in the pane.
+    rows: usize,
+}
+
+impl ListViewport {
+    /// Scroll just enough to keep `selected` in view and return the visible range of a `len`-row list.
+    fn window(&mut self, selected: usize, len: usize) -> std::ops::Range<usize> {
+        let rows = self.rows.max(1);
+        if selected < self.offset {
+            self.offset = selected;
+        } else if selected >= self.offset + rows {
+            self.offset = selected + 1 - rows;
+        }
+        self.offset = self.offset.min(len.saturating_sub(rows));
+        self.offset..(self.offset + rows).min(len)
+    }
+
+    /// Row of `selected` within the visible window.
+    fn relative(&self, selected: usize) -> usize {
+        selected.saturating_sub(self.offset)
+    }
+}
+
+/// Rows of the list pane in a terminal `height` rows tall (mirrors the layout in `draw_ui`: breadcrumb,
+/// tab bar inside an app, then the bordered content pane).
+fn list_rows(height: u16, in_app: bool) -> usize {
+    let chrome = if in_app { 2 + 3 } else { 1 };
+    usize::from(height.saturating_sub(chrome + 2))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_ui_state<'a>(
     current_app: Option<&(u64, String)>,
@@ -979,11 +1928,17 @@ fn build_ui_state<'a>(
     tab_data: &TabData,
     app_list: &[Value],
     app_search: &str,
-    _app_selected: usize,
-    _selected: usize,
-    drill_label: Option<&str>,
+    app_selected: usize,
+    selected: usize,
+    viewport: &mut ListViewport,
+    drill_labels: &[String],
     loading_msg: Option<&str>,
     is_drill_view: bool,
+    filter_editing: bool,
+    time_display: TimeDisplay,
+    list_width: usize,
+    errors_loaded: bool,
+    theme: Theme,
 ) -> (
     Vec<String>,
     Vec<&'static str>,
@@ -999,8 +1954,18 @@ fn build_ui_state<'a>(
             bc.truncate(1);
             bc.push(tab.as_str().to_string());
         }
-        if let Some(l) = drill_label {
-            bc.push(l.to_string());
+        bc.extend(drill_labels.iter().cloned());
+        // Why the list looks the way it does: active filter and sort, e.g. `filter:"user" sort:name↑`.
+        if !is_drill_view {
+            let filter = tab_data.filters.get(&tab).filter(|q| !q.is_empty());
+            let state: Vec<String> = filter
+                .map(|q| format!("filter:\"{}\"", q))
+                .into_iter()
+                .chain(tab_data.sorts.get(&tab).and_then(|s| s.breadcrumb(tab)))
+                .collect();
+            if !state.is_empty() {
+                bc.push(state.join(" "));
+            }
         }
     }
     let tab_names = Tab::all().iter().map(|t| t.as_str()).collect::<Vec<_>>();
@@ -1014,13 +1979,16 @@ fn build_ui_state<'a>(
         }
     } else if current_app.is_none() {
         let indices = filtered_app_indices(app_list, app_search);
-        let items: Vec<ListItem> = indices
+        let window = viewport.window(app_selected, indices.len());
+        let items: Vec<ListItem> = indices[window]
             .iter()
-            .enumerate()
-            .map(|(_, &idx)| {
+            .map(|&idx| {
                 let app = &app_list[idx];
                 let name = app.get("name").and_then(|v| v.as_str()).unwrap_or("?");
-                let id = app.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+                let id = app
+                    .get("id")
+                    .and_then(|v| v.as_u64())
+                    .map_or("?".to_string(), |id| id.to_string());
                 ListItem::new(Line::from(format!("{}  {}", id, name)))
             })
             .collect();
@@ -1034,50 +2002,327 @@ fn build_ui_state<'a>(
         };
         (items, title, None)
     } else if is_drill_view {
-        let title = drill_label
+        let title = drill_labels
+            .last()
             .map(|l| format!(" {} ", l))
             .unwrap_or_else(|| " Detail ".to_string());
         (Vec::new(), title, None) // drill content rendered in draw_ui from Option<DrillContent>
     } else {
-        let items = match tab {
-            Tab::Endpoints => tab_data
-                .endpoints
-                .iter()
-                .enumerate()
-                .map(|(_, (name, _))| ListItem::new(Line::from(name.clone())))
-                .collect(),
-            Tab::Insights => tab_data
-                .insights
-                .iter()
-                .enumerate()
-                .map(|(_, (name, _))| ListItem::new(Line::from(name.clone())))
-                .collect(),
-            Tab::Metrics => tab_data
-                .metrics
-                .iter()
-                .enumerate()
-                .map(|(_, name)| ListItem::new(Line::from(name.clone())))
-                .collect(),
-            Tab::Errors => tab_data
-                .errors
-                .iter()
-                .enumerate()
-                .map(|(_, v)| {
-                    let name = v
-                        .get("message")
-                        .or_else(|| v.get("name"))
-                        .and_then(|n| n.as_str())
-                        .unwrap_or("?");
-                    ListItem::new(Line::from(name.to_string()))
-                })
-                .collect(),
-        };
-        let title = format!(" {} ", tab.as_str());
-        (items, title, None)
+        let visible = tab_data.visible_indices(tab);
+        let error_counts =
+            (tab == Tab::Endpoints && errors_loaded).then(|| errors_by_endpoint(&tab_data.errors));
+        let window = viewport.window(selected, visible.len());
+        let items: Vec<ListItem> = visible[window]
+            .iter()
+            .map(|&i| match tab {
+                Tab::Endpoints => {
+                    let (name, v) = &tab_data.endpoints[i];
+                    let errors = error_counts
+                        .as_ref()
+                        .map(|c| c.get(&name.to_lowercase()).copied().unwrap_or(0));
+                    ListItem::new(endpoint_line(name, v, list_width, errors, theme))
+                }
+                Tab::Traces => {
+                    ListItem::new(Line::from(trace_label(&tab_data.traces[i], time_display)))
+                }
+                Tab::Errors => ListItem::new(error_group_line(&tab_data.errors[i], theme)),
+                _ => ListItem::new(Line::from(tab_data.filter_text(tab, i))),
+            })
+            .collect();
+        let filter = tab_data.filters.get(&tab).filter(|q| !q.is_empty());
+        if let (Some(q), true) = (filter, visible.is_empty() && !filter_editing) {
+            let text = format!(
+                "No {} match filter \"{}\".\n\nPress / to edit the filter or Esc to clear it.",
+                tab.as_str().to_lowercase(),
+                q
+            );
+            (Vec::new(), format!(" {} ", tab.as_str()), Some(text))
+        } else if tab == Tab::Traces && filter.is_none() && !filter_editing {
+            traces_view(tab_data, items, visible.len())
+        } else {
+            let mut title = format!(" {} ", tab.as_str());
+            if filter_editing {
+                title = format!(
+                    " {} — filter: /{}▏ (Enter to keep, Esc to clear) ",
+                    tab.as_str(),
+                    filter.map(String::as_str).unwrap_or("")
+                );
+            } else if let Some(q) = filter {
+                title = format!(
+                    " {} — filter: \"{}\" ({}/{}) ",
+                    tab.as_str(),
+                    q,
+                    visible.len(),
+                    tab_data.raw_len(tab)
+                );
+            }
+            (items, title, None)
+        }
+    };
+    let content_title = match tab_data.sorts.get(&tab).and_then(|s| s.label(tab)) {
+        Some(sort) if current_app.is_some() && !is_drill_view => {
+            format!("{}— {} ", content_title, sort)
+        }
+        _ => content_title,
     };
     (bc, tab_names, list_items, content_title, detail_text)
 }
 
+/// Traces tab list/title, noting the API window (7 days, max 100) and explaining an empty list.
+/// `items` are the visible rows of the `total` traces.
+fn traces_view<'a>(
+    tab_data: &TabData,
+    items: Vec<ListItem<'a>>,
+    total: usize,
+) -> (Vec<ListItem<'a>>, String, Option<String>) {
+    let Some((_, endpoint_name)) = tab_data.trace_endpoint.as_ref() else {
+        let text = "No endpoint selected.\n\nIn the Endpoints tab, select an endpoint and press t \
+                    to list its traces.";
+        return (Vec::new(), " Traces ".to_string(), Some(text.to_string()));
+    };
+    if total == 0 {
+        let text = format!(
+            "No traces for {} in the last 7 days.\n\nThe API only returns traces from the \
+             last 7 days (max {}).",
+            endpoint_name, TRACE_LIST_LIMIT
+        );
+        return (Vec::new(), " Traces ".to_string(), Some(text));
+    }
+    let title = if total >= TRACE_LIST_LIMIT {
+        format!(
+            " Traces — {} (last 7 days, showing API max of {}) ",
+            endpoint_name, TRACE_LIST_LIMIT
+        )
+    } else {
+        format!(
+            " Traces — {} (last 7 days, max {}) ",
+            endpoint_name, TRACE_LIST_LIMIT
+        )
+    };
+    (items, title, None)
+}
+
+/// How long a status message (e.g. export confirmation) stays in the pane title.
+const FLASH_DURATION: std::time::Duration = std::time::Duration::from_secs(4);
+
+/// Write the displayed content as pretty JSON to `scout-<app>-<view>-<timestamp>.json` in `SCOUT_EXPORT_DIR`
+/// (default: current directory). Drill views export their source value, list tabs their visible rows.
+fn export_view(
+    app_id: u64,
+    tab: Tab,
+    tab_data: &TabData,
+    drill: Option<&DrillContent>,
+    drill_label: Option<&str>,
+) -> Result<PathBuf, String> {
+    let value = match drill {
+        Some(DrillContent::Record { value, .. }) | Some(DrillContent::MetricSeries(value)) => {
+            value.clone()
+        }
+        Some(DrillContent::ErrorList { errors, .. }) => Value::Array(errors.clone()),
+        Some(DrillContent::Preformatted(_)) => return Err("nothing to export here".to_string()),
+        None => tab_data.visible_values(tab),
+    };
+    let view: String = drill_label
+        .unwrap_or(tab.as_str())
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let view = view.trim_matches('-');
+    let file_name = format!(
+        "scout-{}-{}-{}.json",
+        app_id,
+        if view.is_empty() { "view" } else { view },
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let dir = std::env::var_os("SCOUT_EXPORT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let path = dir.join(file_name);
+    let json = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+    std::fs::write(&path, json + "\n").map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// JSON of the item under the cursor: the selected error occurrence, the open record or metric series,
+/// or the selected list row. The Metrics list has no data until a series is opened.
+fn selected_value(
+    tab: Tab,
+    tab_data: &TabData,
+    selected: usize,
+    drill: Option<&DrillContent>,
+) -> Result<Value, String> {
+    match drill {
+        Some(DrillContent::ErrorList { errors, selected }) => errors
+            .get(*selected)
+            .cloned()
+            .ok_or_else(|| "no error selected".to_string()),
+        Some(DrillContent::Record { value, .. }) | Some(DrillContent::MetricSeries(value)) => {
+            Ok(value.clone())
+        }
+        Some(DrillContent::Preformatted(_)) => Err("nothing to copy here".to_string()),
+        None if tab == Tab::Metrics => Err("press Enter to load the series first".to_string()),
+        None => tab_data
+            .get_item(tab, selected)
+            .map(|(_, v)| v)
+            .ok_or_else(|| "nothing selected".to_string()),
+    }
+}
+
+/// ScoutAPM web URL of the selected row: an endpoint, a trace of the endpoint listed in the Traces tab, or an
+/// error group. Other tabs have no per-row page.
+fn selected_web_url(
+    app_id: u64,
+    tab: Tab,
+    tab_data: &TabData,
+    selected: usize,
+) -> Result<String, String> {
+    let (_, item) = tab_data
+        .get_item(tab, selected)
+        .ok_or_else(|| "nothing selected".to_string())?;
+    let mut parsed = ParsedScoutUrl {
+        url_type: ScoutUrlType::Unknown,
+        app_id: Some(app_id),
+        endpoint_id: None,
+        trace_id: None,
+        error_id: None,
+        insight_type: None,
+        decoded_endpoint: None,
+        metric_type: None,
+    };
+    let id = || item.get("id").and_then(|v| v.as_u64());
+    match tab {
+        Tab::Endpoints => {
+            parsed.url_type = ScoutUrlType::Endpoint;
+            parsed.endpoint_id = endpoint_id_of(&item);
+        }
+        Tab::Traces => {
+            parsed.url_type = ScoutUrlType::Trace;
+            parsed.endpoint_id = tab_data.trace_endpoint.as_ref().map(|(id, _)| id.clone());
+            parsed.trace_id = id();
+        }
+        Tab::Errors => {
+            parsed.url_type = ScoutUrlType::ErrorGroup;
+            parsed.error_id = id();
+        }
+        Tab::Insights | Tab::Metrics => {
+            return Err(format!("{} rows have no page", tab.as_str().to_lowercase()))
+        }
+    }
+    build_scout_url(&parsed)
+}
+
+/// Copy `text` to the system clipboard. The clipboard handle is opened lazily and kept alive,
+/// since on X11 the copied content is only served while it exists.
+fn copy_to_clipboard(clipboard: &mut Option<arboard::Clipboard>, text: &str) -> Result<(), String> {
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new().map_err(|e| e.to_string())?);
+    }
+    let cb = clipboard.as_mut().expect("clipboard initialized above");
+    cb.set_text(text).map_err(|e| e.to_string())
+}
+
+/// Yank the selected item's JSON to the clipboard; without a clipboard (headless, SSH) write it to a temp file instead.
+/// Returns the status message to flash.
+fn yank_selected(
+    clipboard: &mut Option<arboard::Clipboard>,
+    tab: Tab,
+    tab_data: &TabData,
+    selected: usize,
+    drill: Option<&DrillContent>,
+) -> String {
+    let value = match selected_value(tab, tab_data, selected, drill) {
+        Ok(v) => v,
+        Err(e) => return format!("copy failed: {}", e),
+    };
+    let json = serde_json::to_string_pretty(&value).unwrap_or_else(|_| value.to_string());
+    match copy_to_clipboard(clipboard, &json) {
+        Ok(()) => "copied".to_string(),
+        Err(clip_err) => {
+            let path = std::env::temp_dir().join(format!(
+                "scout-yank-{}.json",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ));
+            match std::fs::write(&path, json + "\n") {
+                Ok(()) => format!("no clipboard ({}); wrote {}", clip_err, path.display()),
+                Err(e) => format!(
+                    "no clipboard ({}); writing {} failed: {}",
+                    clip_err,
+                    path.display(),
+                    e
+                ),
+            }
+        }
+    }
+}
+
+/// Keybindings listed by the `?` help overlay.
+const HELP_KEYS: &[(&str, &str)] = &[
+    ("↑/↓  k/j", "Move selection / scroll detail"),
+    ("PgUp/PgDn", "Scroll detail by a page"),
+    ("Enter", "Open app or selected item"),
+    ("←/→  h/l", "Switch tabs (back out of a detail view)"),
+    ("Esc", "Back (detail → list → app list), clear filter"),
+    ("type", "Search apps (app list)"),
+    ("/", "Filter the active tab"),
+    ("s / S", "Cycle sort key / reverse sort"),
+    ("t", "List traces of the selected endpoint"),
+    ("g", "Toggle bar / line chart (metric view)"),
+    ("J", "Toggle table / raw JSON (detail view)"),
+    ("r", "Reload the current tab (retries a failed load)"),
+    ("e", "Export the current view as JSON"),
+    ("y", "Copy the selected item's JSON to the clipboard"),
+    (
+        "o",
+        "Open the selected endpoint, trace or error group in the browser",
+    ),
+    ("?", "Toggle this help"),
+    ("q", "Quit"),
+];
+
+/// Centered rect of `width` x `height` cells, clamped to `area`.
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// Draw the keybinding help popup on top of the current view.
+fn draw_help(f: &mut Frame, theme: Theme, network: &str) {
+    let key_width = HELP_KEYS
+        .iter()
+        .map(|(k, _)| k.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<Line> = HELP_KEYS
+        .iter()
+        .map(|(key, meaning)| Line::from(format!(" {:<key_width$}  {}", key, meaning)))
+        .collect();
+    lines.push(Line::default());
+    lines.push(Line::from(format!(" {}", network)).style(theme.fg(theme.muted)));
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or(0) as u16 + 3;
+    let area = centered_rect(width, lines.len() as u16 + 2, f.area());
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Help ")
+            .title_bottom(Line::from(" ? or Esc to close ").alignment(Alignment::Right))
+            .borders(Borders::ALL)
+            .border_style(theme.fg(theme.highlight)),
+    );
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
 #[allow(clippy::too_many_arguments)]
 fn draw_ui(
     f: &mut Frame,
@@ -1086,12 +2331,18 @@ fn draw_ui(
     tab_names: &[&str],
     list_items: Vec<ListItem>,
     list_selected: Option<usize>,
+    drill_list_state: &mut ListState,
     loading_indicator: Option<&str>,
+    status_error: Option<&str>,
     content_title: String,
     detail_text: Option<&str>,
     drill: Option<&DrillContent>,
+    detail_scroll: &mut DetailScroll,
     _refresh_secs: u64,
-    use_utc: bool,
+    time_display: TimeDisplay,
+    line_chart: bool,
+    raw_json: bool,
+    theme: Theme,
 ) {
     let is_app_select = content_title.contains("Select an app");
     let has_project = breadcrumb.len() >= 2;
@@ -1116,9 +2367,12 @@ fn draw_ui(
 
     let breadcrumb_block = if has_project {
         let line0 = format!("App: {}", breadcrumb[0]);
-        let line1 = breadcrumb[1..].join(" > ");
+        let mut line1 = vec![Span::raw(breadcrumb[1..].join(" > "))];
+        if let Some(err) = status_error {
+            line1.push(Span::styled(format!("  {}", err), theme.fg(theme.error)));
+        }
         Paragraph::new(vec![Line::from(line0), Line::from(line1)])
-            .style(Style::default().fg(Color::Cyan))
+            .style(theme.fg(theme.accent))
             .block(Block::default().borders(Borders::BOTTOM))
     } else {
         let bc_str = breadcrumb
@@ -1126,14 +2380,14 @@ fn draw_ui(
             .map(String::as_str)
             .unwrap_or("Select app");
         Paragraph::new(bc_str)
-            .style(Style::default().fg(Color::Cyan))
+            .style(theme.fg(theme.accent))
             .block(Block::default().borders(Borders::BOTTOM))
     };
     f.render_widget(breadcrumb_block, breadcrumb_area);
     if let Some(indicator) = loading_indicator {
         let indicator_widget = Paragraph::new(indicator)
             .alignment(Alignment::Right)
-            .style(Style::default().fg(Color::Yellow));
+            .style(theme.fg(theme.highlight));
         f.render_widget(indicator_widget, breadcrumb_area);
     }
 
@@ -1142,53 +2396,105 @@ fn draw_ui(
             .iter()
             .position(|&name| name == current_tab.as_str())
             .unwrap_or(0);
-        let tabs = Tabs::new(tab_names.iter().map(|name| Line::from(format!(" {} ", name))))
-            .select(tab_index)
-            .style(Style::default().fg(Color::Cyan))
-            .highlight_style(
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            );
+        let tabs = Tabs::new(
+            tab_names
+                .iter()
+                .map(|name| Line::from(format!(" {} ", name))),
+        )
+        .select(tab_index)
+        .style(theme.fg(theme.accent))
+        .highlight_style(theme.on_accent().add_modifier(Modifier::BOLD));
         f.render_widget(tabs, vertical[1]);
     }
     let is_loading = content_title.trim() == "Loading";
     let detail_str: Option<String> = match (detail_text, drill) {
         (Some(t), _) => Some(t.to_string()),
-        (None, Some(DrillContent::Preformatted(s))) => Some(s.clone()),
+        (None, Some(DrillContent::Record { value, .. })) if raw_json => {
+            Some(serde_json::to_string_pretty(value).unwrap_or_default())
+        }
+        (None, Some(DrillContent::Preformatted(s)))
+        | (None, Some(DrillContent::Record { text: s, .. })) => Some(s.clone()),
+        (None, Some(DrillContent::ErrorList { errors, .. })) if errors.is_empty() => {
+            Some("No individual errors recorded for this group.".to_string())
+        }
         (None, Some(DrillContent::MetricSeries(_))) => None,
+        (None, Some(DrillContent::ErrorList { .. })) => None,
         (None, None) => None,
     };
     if let Some(DrillContent::MetricSeries(v)) = drill {
-        render_metric_chart(f, content_area, v, use_utc, Some(content_title.trim()));
+        render_metric_chart(
+            f,
+            content_area,
+            v,
+            time_display,
+            Some(content_title.trim()),
+            line_chart,
+            theme,
+        );
+    } else if let (None, Some(DrillContent::ErrorList { errors, selected })) =
+        (detail_str.as_ref(), drill)
+    {
+        let items: Vec<ListItem> = errors
+            .iter()
+            .map(|v| ListItem::new(Line::from(error_label(v, time_display))))
+            .collect();
+        let list = List::new(items)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(
+                Block::default()
+                    .title(format!(
+                        " Errors in group ({}, max 100) — Enter to view ",
+                        errors.len()
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(theme.fg(theme.accent)),
+            );
+        drill_list_state.select(Some(*selected));
+        f.render_stateful_widget(list, content_area, drill_list_state);
     } else if list_items.is_empty() && detail_str.is_none() {
         let empty = Paragraph::new("No data or select an item and press Enter.")
             .block(
                 Block::default()
                     .title(content_title)
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Cyan)),
+                    .border_style(theme.fg(theme.accent)),
             )
-            .style(Style::default().fg(Color::White));
+            .style(theme.fg(theme.text));
         f.render_widget(empty, content_area);
     } else if let Some(text) = detail_str.as_deref() {
-        let border_style = if is_loading {
-            Style::default().fg(Color::Yellow)
+        let border_style = theme.fg(if is_loading {
+            theme.highlight
         } else {
-            Style::default().fg(Color::Cyan)
-        };
+            theme.accent
+        });
+        // Clamp the scroll offset so the last line stays at the bottom of the pane.
+        let total_lines = text.lines().count() as u16;
+        let viewport = content_area.height.saturating_sub(2);
+        let max_offset = total_lines.saturating_sub(viewport);
+        detail_scroll.offset = detail_scroll.offset.min(max_offset);
+        detail_scroll.viewport = viewport;
+        let mut block = Block::default()
+            .title(content_title)
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        if max_offset > 0 {
+            let first = detail_scroll.offset + 1;
+            let last = (detail_scroll.offset + viewport).min(total_lines);
+            block = block.title_bottom(
+                Line::from(format!(
+                    " lines {}–{} of {} (↑/↓ PgUp/PgDn) ",
+                    first, last, total_lines
+                ))
+                .right_aligned(),
+            );
+        }
         let para = Paragraph::new(text)
-            .block(
-                Block::default()
-                    .title(content_title)
-                    .borders(Borders::ALL)
-                    .border_style(border_style),
-            )
-            .style(Style::default().fg(if is_loading {
-                Color::Yellow
+            .block(block)
+            .scroll((detail_scroll.offset, 0))
+            .style(theme.fg(if is_loading {
+                theme.highlight
             } else {
-                Color::White
+                theme.text
             }));
         f.render_widget(para, content_area);
     } else {
@@ -1198,8 +2504,9 @@ fn draw_ui(
                 Block::default()
                     .title(content_title)
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Cyan)),
+                    .border_style(theme.fg(theme.accent)),
             );
+        // Rows are already the visible window (see `ListViewport`), so the state only marks the selection.
         let mut state = ListState::default();
         state.select(list_selected);
         f.render_stateful_widget(list, content_area, &mut state);
@@ -0,0 +1,458 @@
+//! Synchronous facade over [`Client`] for callers without an async runtime (feature `blocking`).
+//!
+//! One tokio runtime is created with the [`BlockingClient`] and shared by its clones; every call blocks
+//! the current thread until the request completes. Do not call it from inside an async task (use
+//! [`Client`] there, or move the call to `spawn_blocking`).
+//!
+//! Every `async` [`Client`] method has a wrapper of the same name and arguments; streams such as
+//! [`Client::list_apps_stream`] have none (drive them with [`BlockingClient::block_on`]).
+
+use crate::client::{Client, Dashboard, EndpointComparison, EndpointTraces, InsightsHistoryPage};
+use crate::error::Error;
+use crate::metrics::{MetricDelta, MetricSummary};
+use serde_json::Value;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking ScoutAPM API client: the [`Client`] methods, run to completion on a shared runtime.
+#[derive(Clone)]
+pub struct BlockingClient {
+    client: Client,
+    rt: Arc<SharedRuntime>,
+}
+
+/// Owns the runtime; shuts it down without waiting so the last clone may be dropped anywhere
+/// (dropping a `Runtime` inside an async context panics).
+struct SharedRuntime(Option<Runtime>);
+
+impl Drop for SharedRuntime {
+    fn drop(&mut self) {
+        if let Some(rt) = self.0.take() {
+            rt.shutdown_background();
+        }
+    }
+}
+
+impl BlockingClient {
+    /// Wrap `client` (configured with its `with_*` builders) and start the shared runtime.
+    pub fn new(client: Client) -> Result<Self, Error> {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("scout-blocking")
+            .enable_all()
+            .build()
+            .map_err(|e| Error::Other(format!("cannot start runtime: {}", e)))?;
+        Ok(Self {
+            client,
+            rt: Arc::new(SharedRuntime(Some(rt))),
+        })
+    }
+
+    /// The wrapped async client.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Run any future (e.g. a [`Client`] method without a wrapper here) to completion.
+    pub fn block_on<F: Future>(&self, f: F) -> F::Output {
+        self.rt
+            .0
+            .as_ref()
+            .expect("runtime lives until drop")
+            .block_on(f)
+    }
+
+    /// See [`Client::get_raw`].
+    pub fn get_raw(&self, path: &str) -> Result<Value, Error> {
+        self.block_on(self.client.get_raw(path))
+    }
+
+    /// See [`Client::list_apps`].
+    pub fn list_apps(&self, active_since: Option<&str>) -> Result<Vec<Value>, Error> {
+        self.block_on(self.client.list_apps(active_since))
+    }
+
+    /// See [`Client::get_app`].
+    pub fn get_app(&self, app_id: u64) -> Result<Value, Error> {
+        self.block_on(self.client.get_app(app_id))
+    }
+
+    /// See [`Client::list_metrics`].
+    pub fn list_metrics(&self, app_id: u64) -> Result<Vec<String>, Error> {
+        self.block_on(self.client.list_metrics(app_id))
+    }
+
+    /// See [`Client::get_metric`].
+    pub fn get_metric(
+        &self,
+        app_id: u64,
+        metric_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Value, Error> {
+        self.block_on(self.client.get_metric(app_id, metric_type, from, to, range))
+    }
+
+    /// See [`Client::get_metric_multi`].
+    #[allow(clippy::type_complexity)]
+    pub fn get_metric_multi(
+        &self,
+        app_ids: &[u64],
+        metric_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Vec<(u64, Result<Value, Error>)>, Error> {
+        self.block_on(
+            self.client
+                .get_metric_multi(app_ids, metric_type, from, to, range),
+        )
+    }
+
+    /// See [`Client::get_metrics`].
+    pub fn get_metrics(
+        &self,
+        app_id: u64,
+        types: &[&str],
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<serde_json::Map<String, Value>, Error> {
+        self.block_on(self.client.get_metrics(app_id, types, from, to, range))
+    }
+
+    /// See [`Client::get_endpoint_histogram`].
+    pub fn get_endpoint_histogram(
+        &self,
+        app_id: u64,
+        endpoint_id: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Value, Error> {
+        self.block_on(
+            self.client
+                .get_endpoint_histogram(app_id, endpoint_id, from, to, range),
+        )
+    }
+
+    /// See [`Client::get_dashboard`].
+    pub fn get_dashboard(&self, app_id: u64, range: &str) -> Result<Dashboard, Error> {
+        self.block_on(self.client.get_dashboard(app_id, range))
+    }
+
+    /// See [`Client::compare_endpoint_across_apps`].
+    pub fn compare_endpoint_across_apps(
+        &self,
+        app_ids: &[u64],
+        endpoint_name: &str,
+        metric_type: &str,
+        range: &str,
+    ) -> Result<Vec<EndpointComparison>, Error> {
+        self.block_on(self.client.compare_endpoint_across_apps(
+            app_ids,
+            endpoint_name,
+            metric_type,
+            range,
+        ))
+    }
+
+    /// See [`Client::get_metric_summary`].
+    pub fn get_metric_summary(
+        &self,
+        app_id: u64,
+        metric_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<MetricSummary, Error> {
+        self.block_on(
+            self.client
+                .get_metric_summary(app_id, metric_type, from, to, range),
+        )
+    }
+
+    /// See [`Client::list_endpoints`].
+    pub fn list_endpoints(
+        &self,
+        app_id: u64,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Value, Error> {
+        self.block_on(self.client.list_endpoints(app_id, from, to, range))
+    }
+
+    /// See [`Client::find_endpoint`].
+    pub fn find_endpoint(&self, app_id: u64, name: &str) -> Result<Option<Value>, Error> {
+        self.block_on(self.client.find_endpoint(app_id, name))
+    }
+
+    /// See [`Client::get_endpoint_metrics_by_name`].
+    pub fn get_endpoint_metrics_by_name(
+        &self,
+        app_id: u64,
+        name: &str,
+        metric_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Value, Error> {
+        self.block_on(self.client.get_endpoint_metrics_by_name(
+            app_id,
+            name,
+            metric_type,
+            from,
+            to,
+            range,
+        ))
+    }
+
+    /// See [`Client::get_endpoint_metrics`].
+    pub fn get_endpoint_metrics(
+        &self,
+        app_id: u64,
+        endpoint_id: &str,
+        metric_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Value, Error> {
+        self.block_on(self.client.get_endpoint_metrics(
+            app_id,
+            endpoint_id,
+            metric_type,
+            from,
+            to,
+            range,
+        ))
+    }
+
+    /// See [`Client::get_endpoint_metric_summary`].
+    pub fn get_endpoint_metric_summary(
+        &self,
+        app_id: u64,
+        endpoint_id: &str,
+        metric_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<MetricSummary, Error> {
+        self.block_on(self.client.get_endpoint_metric_summary(
+            app_id,
+            endpoint_id,
+            metric_type,
+            from,
+            to,
+            range,
+        ))
+    }
+
+    /// See [`Client::get_endpoint_metric_diff`].
+    pub fn get_endpoint_metric_diff(
+        &self,
+        app_id: u64,
+        endpoint_id: &str,
+        metric_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Vec<MetricDelta>, Error> {
+        self.block_on(self.client.get_endpoint_metric_diff(
+            app_id,
+            endpoint_id,
+            metric_type,
+            from,
+            to,
+            range,
+        ))
+    }
+
+    /// See [`Client::list_endpoint_traces`].
+    pub fn list_endpoint_traces(
+        &self,
+        app_id: u64,
+        endpoint_id: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<Value, Error> {
+        self.block_on(
+            self.client
+                .list_endpoint_traces(app_id, endpoint_id, from, to, range),
+        )
+    }
+
+    /// See [`Client::list_endpoint_traces_windowed`].
+    pub fn list_endpoint_traces_windowed(
+        &self,
+        app_id: u64,
+        endpoint_id: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        range: Option<&str>,
+    ) -> Result<EndpointTraces, Error> {
+        self.block_on(self.client.list_endpoint_traces_windowed(
+            app_id,
+            endpoint_id,
+            from,
+            to,
+            range,
+        ))
+    }
+
+    /// See [`Client::list_slow_traces`].
+    pub fn list_slow_traces(
+        &self,
+        app_id: u64,
+        from: Option<&str>,
+        to: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<Value>, Error> {
+        self.block_on(self.client.list_slow_traces(app_id, from, to, limit))
+    }
+
+    /// See [`Client::fetch_trace`].
+    pub fn fetch_trace(&self, app_id: u64, trace_id: u64) -> Result<Value, Error> {
+        self.block_on(self.client.fetch_trace(app_id, trace_id))
+    }
+
+    /// See [`Client::list_error_groups`].
+    pub fn list_error_groups(
+        &self,
+        app_id: u64,
+        from: Option<&str>,
+        to: Option<&str>,
+        endpoint: Option<&str>,
+    ) -> Result<Vec<Value>, Error> {
+        self.block_on(self.client.list_error_groups(app_id, from, to, endpoint))
+    }
+
+    /// See [`Client::get_error_group`].
+    pub fn get_error_group(&self, app_id: u64, error_id: u64) -> Result<Value, Error> {
+        self.block_on(self.client.get_error_group(app_id, error_id))
+    }
+
+    /// See [`Client::get_error_group_errors`].
+    pub fn get_error_group_errors(&self, app_id: u64, error_id: u64) -> Result<Vec<Value>, Error> {
+        self.block_on(self.client.get_error_group_errors(app_id, error_id))
+    }
+
+    /// See [`Client::get_all_insights`].
+    pub fn get_all_insights(&self, app_id: u64, limit: Option<u32>) -> Result<Value, Error> {
+        self.block_on(self.client.get_all_insights(app_id, limit))
+    }
+
+    /// See [`Client::get_insights_filtered`].
+    pub fn get_insights_filtered(
+        &self,
+        app_id: u64,
+        limit: Option<u32>,
+        min_severity: f64,
+    ) -> Result<Value, Error> {
+        self.block_on(
+            self.client
+                .get_insights_filtered(app_id, limit, min_severity),
+        )
+    }
+
+    /// See [`Client::get_insight_by_type`].
+    pub fn get_insight_by_type(
+        &self,
+        app_id: u64,
+        insight_type: &str,
+        limit: Option<u32>,
+    ) -> Result<Value, Error> {
+        self.block_on(self.client.get_insight_by_type(app_id, insight_type, limit))
+    }
+
+    /// See [`Client::get_insights_history`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_insights_history(
+        &self,
+        app_id: u64,
+        from: Option<&str>,
+        to: Option<&str>,
+        limit: Option<u32>,
+        pagination_cursor: Option<u64>,
+        pagination_direction: Option<&str>,
+        pagination_page: Option<u32>,
+    ) -> Result<InsightsHistoryPage, Error> {
+        self.block_on(self.client.get_insights_history(
+            app_id,
+            from,
+            to,
+            limit,
+            pagination_cursor,
+            pagination_direction,
+            pagination_page,
+        ))
+    }
+
+    /// See [`Client::get_insights_history_by_type`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_insights_history_by_type(
+        &self,
+        app_id: u64,
+        insight_type: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+        limit: Option<u32>,
+        pagination_cursor: Option<u64>,
+        pagination_direction: Option<&str>,
+        pagination_page: Option<u32>,
+    ) -> Result<InsightsHistoryPage, Error> {
+        self.block_on(self.client.get_insights_history_by_type(
+            app_id,
+            insight_type,
+            from,
+            to,
+            limit,
+            pagination_cursor,
+            pagination_direction,
+            pagination_page,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocking_client_runs_futures() {
+        let client = BlockingClient::new(Client::new("key".to_string())).unwrap();
+        assert_eq!(client.block_on(async { 1 + 1 }), 2);
+        // Validation errors come back without a request.
+        assert!(client.get_metric(1, "bogus", None, None, None).is_err());
+    }
+
+    #[test]
+    fn every_async_method_has_a_wrapper() {
+        let names = |source: &'static str, prefix: &'static str| {
+            source
+                .lines()
+                .filter_map(move |l| l.trim_start().strip_prefix(prefix))
+                .map(|rest| rest.split(['(', '<']).next().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        let wrapped = names(include_str!("blocking.rs"), "pub fn ");
+        let missing: Vec<_> = names(include_str!("client.rs"), "pub async fn ")
+            .into_iter()
+            .filter(|name| !wrapped.contains(name))
+            .collect();
+        assert!(missing.is_empty(), "no blocking wrapper for {missing:?}");
+    }
+
+    #[tokio::test]
+    async fn dropping_inside_async_context_does_not_panic() {
+        let client = tokio::task::spawn_blocking(|| {
+            BlockingClient::new(Client::new("key".to_string())).unwrap()
+        })
+        .await
+        .unwrap();
+        drop(client);
+    }
+}
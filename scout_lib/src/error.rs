@@ -11,6 +11,11 @@ pub enum Error {
     #[error("API error: {0}")]
     Api(#[from] ApiError),
 
+    /// HTTP 404, or a response without the requested object. `resource` names what was asked for
+    /// (e.g. `trace 5 in app 9`), else the request path.
+    #[error("{resource} not found")]
+    NotFound { resource: String },
+
     #[error("{0}")]
     Other(String),
 }
@@ -32,17 +37,70 @@ pub struct ApiError {
 }
 
 impl ApiError {
+    /// Credential header fields (`X-SCOUT-API`, `Authorization`) in `response_data` are replaced with `***`.
     pub fn new(
         message: impl Into<String>,
         status_code: Option<u16>,
         response_data: Option<serde_json::Value>,
     ) -> Self {
+        let response_data = response_data.map(|mut v| {
+            crate::secret::redact_headers(&mut v);
+            v
+        });
         Self {
             message: message.into(),
             status_code,
             response_data,
         }
     }
+
+    /// The Scout status code: `header.status.code` of the response envelope, else the HTTP status.
+    pub fn code(&self) -> Option<u16> {
+        self.response_data
+            .as_ref()
+            .and_then(|d| d.get("header")?.get("status")?.get("code")?.as_u64())
+            .and_then(|c| u16::try_from(c).ok())
+            .or(self.status_code)
+    }
+
+    /// What kind of failure [`code`](Self::code) denotes.
+    pub fn kind(&self) -> ApiErrorKind {
+        match self.code() {
+            Some(400 | 422) => ApiErrorKind::InvalidParam,
+            Some(403) => ApiErrorKind::Forbidden,
+            Some(429) => ApiErrorKind::RateLimited,
+            Some(500..=599) => ApiErrorKind::Server,
+            _ => ApiErrorKind::Other,
+        }
+    }
+}
+
+/// Classification of an [`ApiError`] by its Scout status code. A 404 is never an [`ApiError`]: the client
+/// reports it as [`Error::NotFound`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// 400 / 422: a parameter was missing or invalid (e.g. a bad time range or metric type).
+    InvalidParam,
+    /// 403: the API key may not access this app.
+    Forbidden,
+    /// 429: too many requests.
+    RateLimited,
+    /// 5xx: server-side failure.
+    Server,
+    Other,
+}
+
+impl ApiErrorKind {
+    /// Snake-case name for machine-readable output (`invalid_param`, `not_found`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::InvalidParam => "invalid_param",
+            Self::Forbidden => "forbidden",
+            Self::RateLimited => "rate_limited",
+            Self::Server => "server",
+            Self::Other => "other",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -57,6 +115,33 @@ mod tests {
         assert!(e.response_data.is_none());
     }
 
+    #[test]
+    fn api_error_new_scrubs_credential_headers() {
+        let data = serde_json::json!({"request": {"headers": {"x-scout-api": "sk-live-123"}}});
+        let e = ApiError::new("bad request", Some(400), Some(data));
+        assert_eq!(
+            e.response_data.unwrap()["request"]["headers"]["x-scout-api"],
+            "***"
+        );
+    }
+
+    #[test]
+    fn api_error_code_and_kind() {
+        let body = serde_json::json!({"header": {"status": {"code": 422, "message": "bad from"}}});
+        let e = ApiError::new("bad from", Some(400), Some(body));
+        assert_eq!(e.code(), Some(422));
+        assert_eq!(e.kind(), ApiErrorKind::InvalidParam);
+        assert_eq!(
+            ApiError::new("x", Some(404), None).kind(),
+            ApiErrorKind::Other
+        );
+        assert_eq!(
+            ApiError::new("x", Some(503), None).kind(),
+            ApiErrorKind::Server
+        );
+        assert_eq!(ApiError::new("x", None, None).kind(), ApiErrorKind::Other);
+    }
+
     #[test]
     fn api_error_display() {
         let e = ApiError::new("not found", Some(404), None);
@@ -81,6 +166,14 @@ mod tests {
         assert!(e.to_string().contains("Authentication failed"));
     }
 
+    #[test]
+    fn not_found_display() {
+        let e = Error::NotFound {
+            resource: "app 9".to_string(),
+        };
+        assert_eq!(e.to_string(), "app 9 not found");
+    }
+
     #[test]
     fn error_from_api() {
         let api = ApiError::new("server error", Some(500), None);
@@ -0,0 +1,196 @@
+//! Saved API responses ("fixtures") for running without network access.
+//!
+//! A fixture is the raw JSON body of one response, stored as `<dir>/<METHOD>_<sanitized-url>.json`
+//! (see [`fixture_file_name`]). With [`Client::with_fixture_dir`](crate::Client::with_fixture_dir) (the
+//! CLI's `--fixtures` or `SCOUT_FIXTURE_DIR`) every request is answered from that directory instead of
+//! the API; [`Client::with_record_dir`](crate::Client::with_record_dir) (the CLI's `--record` or `SCOUT_RECORD_DIR`) fills one from
+//! real responses.
+
+use crate::error::Error;
+use std::path::{Path, PathBuf};
+
+/// Longest sanitized URL kept verbatim in a file name; longer ones are cut before the hash suffix so
+/// names stay below common file-name limits.
+const MAX_URL_CHARS: usize = 160;
+
+/// Query parameters left out of fixture names: the time window, which by default ends now and so
+/// differs on every run.
+const WINDOW_PARAMS: [&str; 2] = ["from", "to"];
+
+/// File name of the fixture for a request: `<METHOD>_<url>.json`, where the URL (without its scheme and
+/// its `from`/`to` parameters) keeps ASCII letters, digits, `-` and `.` and every `/` becomes `_`, e.g.
+/// `GET_scoutapm.com_api_v0_apps_123.json`. When that loses information (a query string, `_` or any
+/// other character) or the URL is long, a hash of the URL is appended so distinct requests never share
+/// a name. The same request always maps to the same name, whatever its time window.
+pub fn fixture_file_name(method: &str, url: &str) -> String {
+    let key = without_window(url);
+    let rest = key.split_once("://").map_or(key.as_str(), |(_, rest)| rest);
+    let mut lossy = rest.contains('_');
+    let mut name: String = rest
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                lossy |= c != '/';
+                '_'
+            }
+        })
+        .collect();
+    if lossy || name.len() > MAX_URL_CHARS {
+        name.truncate(MAX_URL_CHARS);
+        name = format!("{}_{:016x}", name, fnv1a(key.as_bytes()));
+    }
+    format!("{}_{}.json", method.to_ascii_uppercase(), name)
+}
+
+/// `url` without its [`WINDOW_PARAMS`]; a URL without them is returned unchanged.
+fn without_window(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return url.to_string();
+    };
+    if !parsed
+        .query_pairs()
+        .any(|(k, _)| WINDOW_PARAMS.contains(&k.as_ref()))
+    {
+        return url.to_string();
+    }
+    let kept: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(k, _)| !WINDOW_PARAMS.contains(&k.as_ref()))
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    if kept.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(kept);
+    }
+    parsed.into()
+}
+
+/// Path of the fixture for a request in `dir`.
+pub(crate) fn fixture_path(dir: &Path, method: &str, url: &str) -> PathBuf {
+    dir.join(fixture_file_name(method, url))
+}
+
+/// Body of the saved response for a request; a missing or unreadable fixture is an error naming the
+/// file that was expected.
+pub(crate) fn read_fixture(dir: &Path, method: &str, url: &str) -> Result<String, Error> {
+    let path = fixture_path(dir, method, url);
+    std::fs::read_to_string(&path).map_err(|e| {
+        Error::Other(format!(
+            "no fixture for {} {} (expected {}: {})",
+            method,
+            url,
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Save `body` as the fixture for a request in `dir` (created if needed). Refuses a URL that contains
+/// `api_key`, so the key can never end up in a file name.
+pub(crate) fn write_fixture(
+    dir: &Path,
+    method: &str,
+    url: &str,
+    body: &str,
+    api_key: &str,
+) -> Result<(), Error> {
+    if !api_key.is_empty() && url.contains(api_key) {
+        return Err(Error::Other(
+            "not recording a fixture: the request URL contains the API key".to_string(),
+        ));
+    }
+    let path = fixture_path(dir, method, url);
+    std::fs::create_dir_all(dir)
+        .and_then(|()| std::fs::write(&path, body))
+        .map_err(|e| Error::Other(format!("cannot record {}: {}", path.display(), e)))
+}
+
+/// 64-bit FNV-1a: a hash that, unlike `std`'s, is the same in every build, so names stay stable.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixture_file_name_is_sanitized_and_stable() {
+        assert_eq!(
+            fixture_file_name("get", "https://scoutapm.com/api/v0/apps/123"),
+            "GET_scoutapm.com_api_v0_apps_123.json"
+        );
+        let long = format!("https://scoutapm.com/api/v0/apps?q={}", "x".repeat(300));
+        let name = fixture_file_name("GET", &long);
+        assert!(name.len() < 200, "{name}");
+        assert_eq!(name, fixture_file_name("GET", &long));
+        let other = format!("{}y", long);
+        assert_ne!(name, fixture_file_name("GET", &other));
+    }
+
+    #[test]
+    fn fixture_file_name_ignores_the_time_window() {
+        let name = fixture_file_name(
+            "GET",
+            "https://scoutapm.com/api/v0/apps/1/endpoints?from=2025-01-01T00%3A00%3A00Z&to=b&limit=5",
+        );
+        assert_eq!(
+            name,
+            fixture_file_name(
+                "GET",
+                "https://scoutapm.com/api/v0/apps/1/endpoints?from=x&limit=5&to=y"
+            )
+        );
+        assert!(
+            name.starts_with("GET_scoutapm.com_api_v0_apps_1_endpoints_limit_5_"),
+            "{name}"
+        );
+        assert_eq!(
+            fixture_file_name(
+                "GET",
+                "https://scoutapm.com/api/v0/apps/1/metrics/apdex?from=a&to=b"
+            ),
+            "GET_scoutapm.com_api_v0_apps_1_metrics_apdex.json"
+        );
+    }
+
+    #[test]
+    fn fixture_file_name_hashes_lossy_urls() {
+        let names = [
+            "https://scoutapm.com/api/v0/apps/a_b",
+            "https://scoutapm.com/api/v0/apps/a/b",
+            "https://scoutapm.com/api/v0/apps/a?b",
+            "https://scoutapm.com/api/v0/apps/a=b",
+        ]
+        .map(|url| fixture_file_name("GET", url));
+        for (i, a) in names.iter().enumerate() {
+            for b in &names[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_eq!(names[1], "GET_scoutapm.com_api_v0_apps_a_b.json");
+    }
+
+    #[test]
+    fn write_fixture_keeps_the_key_out_of_file_names() {
+        let dir = std::env::temp_dir().join("scout-fixtures-key");
+        let err =
+            write_fixture(&dir, "GET", "https://x/apps?k=sk-123", "{}", "sk-123").unwrap_err();
+        assert!(err.to_string().contains("contains the API key"));
+        assert!(!fixture_path(&dir, "GET", "https://x/apps?k=sk-123").exists());
+    }
+
+    #[test]
+    fn missing_fixture_names_the_expected_file() {
+        let dir = std::env::temp_dir().join("scout-fixtures-missing");
+        let err = read_fixture(&dir, "GET", "https://scoutapm.com/api/v0/apps").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("no fixture for GET"), "{msg}");
+        assert!(msg.contains("GET_scoutapm.com_api_v0_apps.json"), "{msg}");
+    }
+}
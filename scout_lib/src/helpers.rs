@@ -1,7 +1,8 @@
 //! Helpers for API key resolution and ScoutAPM URL parsing.
 
 use base64::Engine;
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local, TimeZone, Utc};
+use serde_json::Value;
 use url::Url;
 
 /// Source from which the API key was obtained (for diagnostics).
@@ -10,15 +11,26 @@ pub enum ApiKeySource {
     OnePassword,
     Bitwarden,
     Keepassxc,
+    LibSecret,
+    AgeFile,
+    /// Windows Credential Manager (Windows builds only).
+    WindowsCredential,
+    /// `SCOUT_API_KEY`, only with the explicit `SCOUT_ALLOW_ENV_KEY=1` opt-in (insecure).
+    EnvVar,
 }
 
-/// Get API key from a secret backend only (1Password, Bitwarden, KeePassXC).
+/// Get API key from a secret backend (1Password, Bitwarden, KeePassXC, libsecret, age, Windows Credential Manager).
 ///
-/// Plain-text API keys (env vars or CLI) are not supported for security reasons.
-/// Configure one backend via its env vars (see [secret] module):
-/// - 1Password: `SCOUT_OP_ENTRY_PATH` (op://Vault/Item) or `SCOUT_OP_VAULT` + `SCOUT_OP_ITEM`; optional `SCOUT_OP_FIELD` (default API_KEY).
-/// - Bitwarden: `SCOUT_BW_ITEM_ID` (login item UUID); optional `SCOUT_BW_SESSION`.
+/// Plain-text API keys are not supported for security reasons, except `SCOUT_API_KEY` behind the explicit
+/// `SCOUT_ALLOW_ENV_KEY=1` opt-in, which is tried last. Configure one backend via its env vars (see [secret] module):
+/// - 1Password: `SCOUT_OP_ENTRY_PATH` (op://Vault/Item) or `SCOUT_OP_VAULT` + `SCOUT_OP_ITEM`; optional `SCOUT_OP_FIELD` (default API_KEY) and `SCOUT_OP_ACCOUNT`.
+/// - Bitwarden: `SCOUT_BW_ITEM_ID` (login item UUID) or `SCOUT_BW_ITEM_NAME`; optional `SCOUT_BW_FIELD`,
+///   `SCOUT_BW_SESSION`.
 /// - KeePassXC: `SCOUT_KPXC_DB`, `SCOUT_KPXC_ENTRY`; optional `SCOUT_KPXC_ATTRIBUTE` (default Password).
+/// - libsecret (GNOME keyring): `SCOUT_SECRET_TOOL_ATTRS` (attribute/value pairs, e.g. `service scout`).
+/// - age: `SCOUT_AGE_FILE` (encrypted file), `SCOUT_AGE_IDENTITY` (identity file).
+/// - Windows Credential Manager (Windows only): `SCOUT_WINCRED_TARGET` (credential resource name).
+/// - Environment variable (insecure, CI only): `SCOUT_API_KEY` with `SCOUT_ALLOW_ENV_KEY=1`.
 pub fn get_api_key() -> Result<(String, ApiKeySource), String> {
     if let Some(k) = crate::secret::one_password() {
         if !k.is_empty() {
@@ -35,9 +47,29 @@ pub fn get_api_key() -> Result<(String, ApiKeySource), String> {
             return Ok((k, ApiKeySource::Keepassxc));
         }
     }
+    if let Some(k) = crate::secret::libsecret() {
+        if !k.is_empty() {
+            return Ok((k, ApiKeySource::LibSecret));
+        }
+    }
+    if let Some(k) = crate::secret::age_file() {
+        if !k.is_empty() {
+            return Ok((k, ApiKeySource::AgeFile));
+        }
+    }
+    #[cfg(windows)]
+    if let Some(k) = crate::secret::windows_credential() {
+        return Ok((k, ApiKeySource::WindowsCredential));
+    }
+    if let Some(k) = crate::secret::env_var() {
+        return Ok((k, ApiKeySource::EnvVar));
+    }
     Err(
         "API key not found. Configure a secret backend: SCOUT_OP_ENTRY_PATH (1Password), \
-         SCOUT_BW_ITEM_ID (Bitwarden), or SCOUT_KPXC_DB+SCOUT_KPXC_ENTRY (KeePassXC). Plain-text keys are not supported."
+         SCOUT_BW_ITEM_ID or SCOUT_BW_ITEM_NAME (Bitwarden), SCOUT_KPXC_DB+SCOUT_KPXC_ENTRY (KeePassXC), \
+         SCOUT_SECRET_TOOL_ATTRS (libsecret), SCOUT_AGE_FILE+SCOUT_AGE_IDENTITY (age), \
+         or SCOUT_WINCRED_TARGET (Windows Credential Manager). Plain-text keys are not supported \
+         (except SCOUT_API_KEY with SCOUT_ALLOW_ENV_KEY=1, insecure, for CI containers)."
             .to_string(),
     )
 }
@@ -51,6 +83,8 @@ pub enum ScoutUrlType {
     Trace,
     ErrorGroup,
     Insight,
+    /// A metric chart (`/apps/<id>/metrics/<type>` or a dashboard `/apps/<id>/charts/<type>`).
+    Metric,
     Unknown,
 }
 
@@ -64,6 +98,7 @@ pub struct ParsedScoutUrl {
     pub error_id: Option<u64>,
     pub insight_type: Option<String>,
     pub decoded_endpoint: Option<String>,
+    pub metric_type: Option<String>,
 }
 
 /// Parse a ScoutAPM URL and extract resource identifiers.
@@ -83,6 +118,8 @@ pub fn parse_scout_url(url: &str) -> Result<ParsedScoutUrl, String> {
         ScoutUrlType::ErrorGroup
     } else if segments.contains(&"insights") {
         ScoutUrlType::Insight
+    } else if segments.contains(&"metrics") || segments.contains(&"charts") {
+        ScoutUrlType::Metric
     } else if app_index.is_some() && segments.len() >= 2 && segments[0] == "apps" {
         ScoutUrlType::App
     } else {
@@ -105,6 +142,11 @@ pub fn parse_scout_url(url: &str) -> Result<ParsedScoutUrl, String> {
         .iter()
         .position(|s| *s == "insights")
         .and_then(|i| segments.get(i + 1).map(|s| (*s).to_string()));
+    let metric_type = segments
+        .iter()
+        .position(|s| *s == "metrics" || *s == "charts")
+        .and_then(|i| segments.get(i + 1).map(|s| (*s).to_string()))
+        .filter(|s| !s.is_empty());
 
     let decoded_endpoint = endpoint_id
         .as_ref()
@@ -118,6 +160,63 @@ pub fn parse_scout_url(url: &str) -> Result<ParsedScoutUrl, String> {
         error_id,
         insight_type,
         decoded_endpoint,
+        metric_type,
+    })
+}
+
+/// Base URL of the ScoutAPM web UI, used by [`build_scout_url`].
+const SCOUT_WEB_BASE: &str = "https://scoutapm.com";
+
+/// Build the canonical ScoutAPM URL for a parsed resource (inverse of [`parse_scout_url`]).
+///
+/// Needs `app_id`, plus `endpoint_id` (or `decoded_endpoint`, which is encoded) for endpoints and traces,
+/// `trace_id` for traces, `error_id` for error groups, `insight_type` for insights and `metric_type`
+/// for metrics.
+pub fn build_scout_url(parsed: &ParsedScoutUrl) -> Result<String, String> {
+    let missing = |what: &str| format!("{:?} URL needs {}", parsed.url_type, what);
+    if parsed.url_type == ScoutUrlType::Unknown {
+        return Err("cannot build a URL for an unknown resource type".to_string());
+    }
+    let app_id = parsed.app_id.ok_or_else(|| missing("an app id"))?;
+    let app_url = format!("{}/apps/{}", SCOUT_WEB_BASE, app_id);
+    let endpoint_id = || {
+        parsed
+            .endpoint_id
+            .clone()
+            .or_else(|| parsed.decoded_endpoint.as_deref().map(encode_endpoint_id))
+            .ok_or_else(|| missing("an endpoint id or name"))
+    };
+    Ok(match parsed.url_type {
+        ScoutUrlType::App => app_url,
+        ScoutUrlType::Endpoint => format!("{}/endpoints/{}", app_url, endpoint_id()?),
+        ScoutUrlType::Trace => {
+            let trace_id = parsed.trace_id.ok_or_else(|| missing("a trace id"))?;
+            format!(
+                "{}/endpoints/{}/trace/{}",
+                app_url,
+                endpoint_id()?,
+                trace_id
+            )
+        }
+        ScoutUrlType::ErrorGroup => {
+            let error_id = parsed.error_id.ok_or_else(|| missing("an error id"))?;
+            format!("{}/error_groups/{}", app_url, error_id)
+        }
+        ScoutUrlType::Insight => {
+            let insight = parsed
+                .insight_type
+                .as_deref()
+                .ok_or_else(|| missing("an insight type"))?;
+            format!("{}/insights/{}", app_url, insight)
+        }
+        ScoutUrlType::Metric => {
+            let metric = parsed
+                .metric_type
+                .as_deref()
+                .ok_or_else(|| missing("a metric type"))?;
+            format!("{}/metrics/{}", app_url, metric)
+        }
+        ScoutUrlType::Unknown => unreachable!("rejected above"),
     })
 }
 
@@ -130,29 +229,127 @@ pub fn decode_endpoint_id(endpoint_id: &str) -> Result<String, String> {
     String::from_utf8(decoded).map_err(|e| e.to_string())
 }
 
+/// Encode an endpoint (transaction) name such as `Controller#action` as the base64url endpoint ID used by the API.
+pub fn encode_endpoint_id(name: &str) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(name.as_bytes())
+}
+
+/// Endpoint id used by the API (base64url name), taken from the endpoint's `link` path or encoded from its name.
+pub fn endpoint_id_of(endpoint: &serde_json::Value) -> Option<String> {
+    endpoint
+        .get("link")
+        .and_then(|l| l.as_str())
+        .and_then(|l| l.trim_end_matches('/').rsplit('/').next())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .or_else(|| {
+            endpoint
+                .get("name")
+                .and_then(|n| n.as_str())
+                .map(encode_endpoint_id)
+        })
+}
+
+/// The `key` list of a response, wherever the envelope puts it: `results.<key>`, `results` itself
+/// (an array), top-level `<key>`, or the value itself (an array, e.g. an already unwrapped `results`).
+/// Empty when none of these is an array.
+pub fn extract_list<'a>(v: &'a Value, key: &str) -> &'a [Value] {
+    let results = v.get("results");
+    results
+        .and_then(|r| r.get(key))
+        .and_then(|l| l.as_array())
+        .or_else(|| results.and_then(|r| r.as_array()))
+        .or_else(|| v.get(key).and_then(|l| l.as_array()))
+        .or_else(|| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
 /// Format time as ISO 8601 for the API.
 pub fn format_time(dt: DateTime<Utc>) -> String {
     dt.format("%Y-%m-%dT%H:%M:%SZ").to_string()
 }
 
+/// How [`format_timestamp_as`] shows a timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeDisplay {
+    /// Absolute, in the local timezone.
+    #[default]
+    Local,
+    /// Absolute, in UTC.
+    Utc,
+    /// Relative to now, e.g. "5m ago" (see [`humanize_timestamp`]).
+    Relative,
+}
+
 /// Format an ISO 8601 timestamp for display. If `use_utc` is true, shows UTC; otherwise converts to local timezone.
 /// On parse failure returns the original string unchanged.
 pub fn format_timestamp_display(ts: &str, use_utc: bool) -> String {
+    let display = if use_utc {
+        TimeDisplay::Utc
+    } else {
+        TimeDisplay::Local
+    };
+    format_timestamp_as(ts, display)
+}
+
+/// Format an ISO 8601 timestamp the way `display` says. On parse failure returns the original string unchanged.
+pub fn format_timestamp_as(ts: &str, display: TimeDisplay) -> String {
     let dt = match parse_time(ts) {
         Ok(d) => d,
         _ => return ts.to_string(),
     };
-    if use_utc {
-        dt.format("%Y-%m-%d %H:%M:%S UTC").to_string()
-    } else {
-        dt.with_timezone(&Local)
+    match display {
+        TimeDisplay::Utc => dt.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+        TimeDisplay::Local => dt
+            .with_timezone(&Local)
             .format("%Y-%m-%d %H:%M:%S %:z")
-            .to_string()
+            .to_string(),
+        TimeDisplay::Relative => humanize_since(dt, Utc::now()),
     }
 }
 
-/// Parse ISO 8601 time string.
+/// A timestamp (any [`parse_time`] form) relative to now: "just now", "5m ago", "2d ago", or "in 30s" for
+/// future times. On parse failure returns the original string unchanged.
+pub fn humanize_timestamp(ts: &str) -> String {
+    match parse_time(ts) {
+        Ok(dt) => humanize_since(dt, Utc::now()),
+        Err(_) => ts.to_string(),
+    }
+}
+
+/// `dt` relative to `now` in the largest whole unit (s, m, h, d); under 10 seconds ago is "just now".
+fn humanize_since(dt: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = (now - dt).num_seconds();
+    if (0..10).contains(&secs) {
+        return "just now".to_string();
+    }
+    let abs = secs.unsigned_abs();
+    let amount = match abs {
+        0..=59 => format!("{}s", abs),
+        60..=3599 => format!("{}m", abs / 60),
+        3600..=86399 => format!("{}h", abs / 3600),
+        _ => format!("{}d", abs / 86400),
+    };
+    if secs < 0 {
+        format!("in {}", amount)
+    } else {
+        format!("{} ago", amount)
+    }
+}
+
+/// Parse ISO 8601 time string, a plain date (`2024-01-01`, midnight UTC), or Unix epoch seconds written
+/// as `@<secs>` or as 10+ digits (shorter numbers are too easily a year).
 pub fn parse_time(s: &str) -> Result<DateTime<Utc>, String> {
+    if let Some(secs) = epoch_secs(s) {
+        return Utc
+            .timestamp_opt(secs, 0)
+            .single()
+            .ok_or_else(|| format!("epoch seconds out of range: {}", s.trim()));
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
     let s = s.trim().trim_end_matches('Z').trim_end_matches('z');
     let parsed = chrono::DateTime::parse_from_rfc3339(&format!("{}Z", s))
         .or_else(|_| chrono::DateTime::parse_from_rfc3339(s))
@@ -160,6 +357,169 @@ pub fn parse_time(s: &str) -> Result<DateTime<Utc>, String> {
     Ok(parsed.with_timezone(&Utc))
 }
 
+/// Keep the items whose `field` timestamp is at or after `since` (any [`parse_time`] form). Items
+/// without a parseable `field` are dropped.
+pub fn filter_by_time(items: Vec<Value>, field: &str, since: &str) -> Result<Vec<Value>, String> {
+    let since = parse_time(since)?;
+    Ok(items
+        .into_iter()
+        .filter(|item| {
+            item.get(field)
+                .and_then(|v| v.as_str())
+                .and_then(|s| parse_time(s).ok())
+                .is_some_and(|t| t >= since)
+        })
+        .collect())
+}
+
+/// Occurrence count of an error group: `errors_count`, `count` or `occurrences`, the first present.
+fn error_count(group: &Value) -> Option<f64> {
+    ["errors_count", "count", "occurrences"]
+        .iter()
+        .find_map(|k| group.get(*k).and_then(|v| v.as_f64()))
+}
+
+/// Add `share_pct` (percent of all occurrences, one decimal) to each error group that has a count.
+/// Groups without one are left as they are; nothing is added when no group has a non-zero count.
+pub fn annotate_error_share(groups: &mut [Value]) {
+    let total: f64 = groups.iter().filter_map(error_count).sum();
+    if total <= 0.0 {
+        return;
+    }
+    for group in groups.iter_mut() {
+        if let (Some(n), Some(obj)) = (error_count(group), group.as_object_mut()) {
+            let pct = (n / total * 1000.0).round() / 10.0;
+            obj.insert("share_pct".to_string(), pct.into());
+        }
+    }
+}
+
+/// Named severity levels, lowest first; their rank (1-based) is the numeric severity.
+const SEVERITY_LEVELS: [&str; 4] = ["low", "medium", "high", "critical"];
+
+/// Numeric severity of a named level (`low` 1 .. `critical` 4) or a number, case-insensitive.
+pub fn parse_severity(s: &str) -> Result<f64, String> {
+    let s = s.trim().to_lowercase();
+    if let Some(i) = SEVERITY_LEVELS.iter().position(|l| *l == s) {
+        return Ok((i + 1) as f64);
+    }
+    s.parse::<f64>().map_err(|_| {
+        format!(
+            "Invalid severity '{}': use {} or a number",
+            s,
+            SEVERITY_LEVELS.join(", ")
+        )
+    })
+}
+
+/// Severity of an insight from its `severity` or `impact` field, either a level name or a number.
+/// `None` when neither is present or recognizable.
+pub fn insight_severity(insight: &Value) -> Option<f64> {
+    ["severity", "impact"]
+        .iter()
+        .filter_map(|k| insight.get(*k))
+        .find_map(|v| match v {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => parse_severity(s).ok(),
+            _ => None,
+        })
+}
+
+/// Keep only insights with a severity of at least `min`, highest first. Applies to each per-type array
+/// of an insights object (`{"n_plus_one": [...], ...}`) or to a top-level array; insights without a
+/// severity are dropped.
+pub fn filter_insights_by_severity(insights: &mut Value, min: f64) {
+    let filter = |list: &mut Vec<Value>| {
+        list.retain(|i| insight_severity(i).is_some_and(|s| s >= min));
+        list.sort_by(|a, b| {
+            let (a, b) = (insight_severity(a), insight_severity(b));
+            b.unwrap_or(0.0).total_cmp(&a.unwrap_or(0.0))
+        });
+    };
+    match insights {
+        Value::Array(list) => filter(list),
+        Value::Object(by_type) => {
+            for list in by_type.values_mut() {
+                if let Value::Array(list) = list {
+                    filter(list);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Duration of a trace from its `total_call_time` field (a number or numeric string); `None` when it
+/// is absent or not numeric.
+pub fn trace_duration(trace: &Value) -> Option<f64> {
+    match trace.get("total_call_time")? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Sort traces by [`trace_duration`], slowest first; traces without a duration keep their order at the
+/// end. Applies to a top-level array or to the array under `traces` or `results` (as returned by
+/// [`Client::list_endpoint_traces`](crate::Client::list_endpoint_traces)).
+pub fn sort_traces_slowest(traces: &mut Value) {
+    let list = match traces {
+        Value::Array(list) => list,
+        Value::Object(obj) => match ["traces", "results"]
+            .iter()
+            .find(|k| obj.get(**k).is_some_and(Value::is_array))
+        {
+            Some(k) => match obj.get_mut(*k) {
+                Some(Value::Array(list)) => list,
+                _ => return,
+            },
+            None => return,
+        },
+        _ => return,
+    };
+    sort_slowest_first(list);
+}
+
+/// [`sort_traces_slowest`] for a plain list.
+pub(crate) fn sort_slowest_first(list: &mut [Value]) {
+    list.sort_by(|a, b| match (trace_duration(a), trace_duration(b)) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+}
+
+/// Seconds of an epoch time (`@1700000000` or `1700000000`); `None` when `s` is not in epoch form.
+fn epoch_secs(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let digits = match s.strip_prefix('@') {
+        Some(d) => d,
+        None if s.len() >= 10 => s,
+        None => return None,
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// A `--from`/`--to` value as sent to the API: epoch seconds become ISO 8601, anything else is passed
+/// through unchanged.
+pub fn normalize_time(s: &str) -> Result<String, String> {
+    if epoch_secs(s).is_some() {
+        return parse_time(s).map(format_time);
+    }
+    if s.trim().starts_with('@') {
+        return Err(format!("invalid epoch seconds: {}", s.trim()));
+    }
+    Ok(s.to_string())
+}
+
+/// Units accepted by [`parse_range`], appended to its errors.
+const RANGE_UNITS_HELP: &str =
+    "supported units: min (minutes), hr/hour (hours), day (days); e.g. 30min, 3hours, 7days";
+
 /// Parse range string (e.g. "30min", "1day", "7days") into seconds.
 pub fn parse_range(range_str: &str) -> Result<u64, String> {
     let s = range_str.trim().to_lowercase();
@@ -174,13 +534,18 @@ pub fn parse_range(range_str: &str) -> Result<u64, String> {
     }
     let num: u64 = s[..num_end]
         .parse()
-        .map_err(|_| format!("Invalid range: {}", range_str))?;
+        .map_err(|_| format!("Invalid range '{}': {}", range_str, RANGE_UNITS_HELP))?;
     let unit = s[num_end..].trim();
     let secs = match unit {
         u if u.starts_with("min") => num * 60,
         u if u.starts_with("hr") || u.starts_with("hour") => num * 3600,
         u if u.starts_with("day") => num * 86400,
-        _ => return Err(format!("Unknown time unit in range: {}", range_str)),
+        _ => {
+            return Err(format!(
+                "Unknown time unit in range '{}': {}",
+                range_str, RANGE_UNITS_HELP
+            ))
+        }
     };
     Ok(secs)
 }
@@ -196,6 +561,13 @@ pub fn calculate_range(range: &str, to: Option<&str>) -> Result<(String, String)
     Ok((format_time(start_time), format_time(end_time)))
 }
 
+/// The window of the same length immediately before `from`..`to` (it ends at `from`), for
+/// comparing a period with the previous one.
+pub fn previous_range(from: &str, to: &str) -> Result<(String, String), String> {
+    let (start, end) = (parse_time(from)?, parse_time(to)?);
+    Ok((format_time(start - (end - start)), format_time(start)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,12 +582,97 @@ mod tests {
         assert_eq!(parse_range("1hr").unwrap(), 3600);
     }
 
+    #[test]
+    fn test_previous_range() {
+        let (from, to) = calculate_range("7days", Some("2025-01-15T00:00:00Z")).unwrap();
+        assert_eq!(from, "2025-01-08T00:00:00Z");
+        let (prev_from, prev_to) = previous_range(&from, &to).unwrap();
+        assert_eq!(prev_from, "2025-01-01T00:00:00Z");
+        assert_eq!(prev_to, "2025-01-08T00:00:00Z");
+        assert!(previous_range("yesterday", &to).is_err());
+    }
+
+    #[test]
+    fn insight_severity_filter_and_sort() {
+        assert_eq!(parse_severity("High"), Ok(3.0));
+        assert_eq!(parse_severity("2.5"), Ok(2.5));
+        assert!(parse_severity("urgent")
+            .unwrap_err()
+            .contains("low, medium"));
+        assert_eq!(
+            insight_severity(&serde_json::json!({"impact": 7})),
+            Some(7.0)
+        );
+        assert_eq!(
+            insight_severity(&serde_json::json!({"severity": "low"})),
+            Some(1.0)
+        );
+        assert_eq!(
+            insight_severity(&serde_json::json!({"severity": null})),
+            None
+        );
+
+        let mut v = serde_json::json!({
+            "n_plus_one": [
+                {"id": 1, "severity": "medium"},
+                {"id": 2, "severity": "critical"},
+                {"id": 3},
+                {"id": 4, "impact": 3},
+            ],
+            "slow_query": [{"id": 5, "severity": "low"}],
+            "count": 5,
+        });
+        filter_insights_by_severity(&mut v, 3.0);
+        let ids: Vec<_> = v["n_plus_one"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["id"].as_u64().unwrap())
+            .collect();
+        assert_eq!(ids, vec![2, 4]);
+        assert_eq!(v["slow_query"], serde_json::json!([]));
+        assert_eq!(v["count"], 5);
+    }
+
+    #[test]
+    fn traces_sort_slowest_first() {
+        let mut v = serde_json::json!({"traces": [
+            {"id": 1, "total_call_time": 0.2},
+            {"id": 2},
+            {"id": 3, "total_call_time": "1.5"},
+            {"id": 4, "total_call_time": "n/a"},
+            {"id": 5, "total_call_time": 0.9},
+        ]});
+        sort_traces_slowest(&mut v);
+        let ids: Vec<_> = v["traces"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["id"].as_u64().unwrap())
+            .collect();
+        assert_eq!(ids, vec![3, 5, 1, 2, 4]);
+
+        let mut results =
+            serde_json::json!({"results": [{"total_call_time": 1}, {"total_call_time": 2}]});
+        sort_traces_slowest(&mut results);
+        assert_eq!(results["results"][0]["total_call_time"], 2);
+        let mut other = serde_json::json!({"count": 1});
+        sort_traces_slowest(&mut other);
+        assert_eq!(other, serde_json::json!({"count": 1}));
+    }
+
     #[test]
     fn test_parse_range_errors() {
         assert!(parse_range("").is_err());
         assert!(parse_range("min").is_err());
         assert!(parse_range("7").is_err());
         assert!(parse_range("7weeks").is_err());
+        let msg = parse_range("1week").unwrap_err();
+        assert!(msg.contains("'1week'"), "{msg}");
+        for hint in ["min", "hr/hour", "day", "e.g. 30min"] {
+            assert!(msg.contains(hint), "{msg}");
+        }
+        assert!(parse_range("day").unwrap_err().contains("supported units"));
     }
 
     #[test]
@@ -260,6 +717,58 @@ mod tests {
         assert_eq!(p.insight_type.as_deref(), Some("n_plus_one"));
     }
 
+    #[test]
+    fn test_parse_scout_url_metric() {
+        let p = parse_scout_url("https://scoutapm.com/apps/123/metrics/response_time").unwrap();
+        assert_eq!(p.url_type, ScoutUrlType::Metric);
+        assert_eq!(p.app_id, Some(123));
+        assert_eq!(p.metric_type.as_deref(), Some("response_time"));
+        let p =
+            parse_scout_url("https://scoutapm.com/apps/123/charts/throughput?range=1day").unwrap();
+        assert_eq!(p.url_type, ScoutUrlType::Metric);
+        assert_eq!(p.metric_type.as_deref(), Some("throughput"));
+        let p = parse_scout_url("https://scoutapm.com/apps/123/endpoints/abc").unwrap();
+        assert_eq!(p.url_type, ScoutUrlType::Endpoint);
+        assert_eq!(p.metric_type, None);
+    }
+
+    #[test]
+    fn test_build_scout_url_round_trip() {
+        for u in [
+            "https://scoutapm.com/apps/42",
+            "https://scoutapm.com/apps/1/endpoints/Zm9vL2Jhcg",
+            "https://scoutapm.com/apps/123/endpoints/abc/trace/456",
+            "https://scoutapm.com/apps/10/error_groups/789",
+            "https://scoutapm.com/apps/5/insights/n_plus_one",
+            "https://scoutapm.com/apps/5/metrics/response_time",
+        ] {
+            assert_eq!(build_scout_url(&parse_scout_url(u).unwrap()).unwrap(), u);
+        }
+    }
+
+    #[test]
+    fn test_build_scout_url_encodes_endpoint_name() {
+        let p = ParsedScoutUrl {
+            url_type: ScoutUrlType::Endpoint,
+            app_id: Some(1),
+            endpoint_id: None,
+            trace_id: None,
+            error_id: None,
+            insight_type: None,
+            decoded_endpoint: Some("foo/bar".to_string()),
+            metric_type: None,
+        };
+        assert_eq!(
+            build_scout_url(&p).unwrap(),
+            "https://scoutapm.com/apps/1/endpoints/Zm9vL2Jhcg"
+        );
+        let no_trace = ParsedScoutUrl {
+            url_type: ScoutUrlType::Trace,
+            ..p
+        };
+        assert!(build_scout_url(&no_trace).unwrap_err().contains("trace id"));
+    }
+
     #[test]
     fn test_parse_scout_url_invalid() {
         assert!(parse_scout_url("not-a-url").is_err());
@@ -278,6 +787,23 @@ mod tests {
         assert_eq!(decoded, "foo");
     }
 
+    #[test]
+    fn test_encode_endpoint_id() {
+        assert_eq!(encode_endpoint_id("foo/bar"), "Zm9vL2Jhcg");
+        for name in ["Controller/UsersController#show", "Job/Mailer?x=1&y=ü", ""] {
+            assert_eq!(decode_endpoint_id(&encode_endpoint_id(name)).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn test_endpoint_id_of() {
+        let e = serde_json::json!({"name": "foo/bar", "link": "/apps/1/endpoints/abc/"});
+        assert_eq!(endpoint_id_of(&e).as_deref(), Some("abc"));
+        let e = serde_json::json!({"name": "foo/bar"});
+        assert_eq!(endpoint_id_of(&e).as_deref(), Some("Zm9vL2Jhcg"));
+        assert_eq!(endpoint_id_of(&serde_json::json!({})), None);
+    }
+
     #[test]
     fn test_decode_endpoint_id_invalid() {
         assert!(decode_endpoint_id("!!!").is_err());
@@ -292,6 +818,92 @@ mod tests {
         assert_eq!(t2, t);
     }
 
+    #[test]
+    fn test_parse_time_epoch() {
+        let expected = Utc.with_ymd_and_hms(2023, 11, 14, 22, 13, 20).unwrap();
+        assert_eq!(parse_time("@1700000000").unwrap(), expected);
+        assert_eq!(parse_time("1700000000").unwrap(), expected);
+        assert_eq!(parse_time("@0").unwrap().timestamp(), 0);
+        // Short all-digit strings are not taken as epochs.
+        assert!(parse_time("2025").is_err());
+        assert!(parse_time("@").is_err());
+        assert!(parse_time("@17e8").is_err());
+    }
+
+    #[test]
+    fn test_extract_list_shapes() {
+        let apps = serde_json::json!([{"id": 1}]);
+        for res in [
+            serde_json::json!({"results": {"apps": apps}}),
+            serde_json::json!({"results": apps}),
+            serde_json::json!({"apps": apps}),
+            apps.clone(),
+        ] {
+            assert_eq!(
+                extract_list(&res, "apps"),
+                apps.as_array().unwrap().as_slice()
+            );
+        }
+        assert!(extract_list(&serde_json::json!({"results": {}}), "apps").is_empty());
+        assert!(extract_list(&serde_json::json!(null), "apps").is_empty());
+    }
+
+    #[test]
+    fn test_filter_by_time() {
+        let items = vec![
+            serde_json::json!({"name": "old", "last_seen": "2023-12-31T23:59:59Z"}),
+            serde_json::json!({"name": "new", "last_seen": "2024-01-01T00:00:00Z"}),
+            serde_json::json!({"name": "none"}),
+        ];
+        let kept = filter_by_time(items, "last_seen", "2024-01-01").unwrap();
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0]["name"], "new");
+        assert!(filter_by_time(vec![], "last_seen", "yesterday").is_err());
+    }
+
+    #[test]
+    fn test_humanize_since() {
+        let now = parse_time("2025-01-15T12:00:00Z").unwrap();
+        let at = |s: &str| humanize_since(parse_time(s).unwrap(), now);
+        assert_eq!(at("2025-01-15T11:59:55Z"), "just now");
+        assert_eq!(at("2025-01-15T11:59:30Z"), "30s ago");
+        assert_eq!(at("2025-01-15T11:55:00Z"), "5m ago");
+        assert_eq!(at("2025-01-15T09:00:00Z"), "3h ago");
+        assert_eq!(at("2025-01-13T12:00:00Z"), "2d ago");
+        assert_eq!(at("2025-01-15T12:00:30Z"), "in 30s");
+        assert_eq!(at("2025-01-15T14:00:00Z"), "in 2h");
+        assert_eq!(humanize_timestamp("not a time"), "not a time");
+    }
+
+    #[test]
+    fn test_annotate_error_share() {
+        let mut groups = vec![
+            serde_json::json!({"id": 1, "errors_count": 3}),
+            serde_json::json!({"id": 2, "count": 1}),
+            serde_json::json!({"id": 3}),
+        ];
+        annotate_error_share(&mut groups);
+        assert_eq!(groups[0]["share_pct"], 75.0);
+        assert_eq!(groups[1]["share_pct"], 25.0);
+        assert!(groups[2].get("share_pct").is_none());
+        let mut none = vec![serde_json::json!({"id": 1})];
+        annotate_error_share(&mut none);
+        assert!(none[0].get("share_pct").is_none());
+    }
+
+    #[test]
+    fn test_normalize_time() {
+        assert_eq!(
+            normalize_time("1700000000").unwrap(),
+            "2023-11-14T22:13:20Z"
+        );
+        assert_eq!(
+            normalize_time("2025-01-15T12:00:00Z").unwrap(),
+            "2025-01-15T12:00:00Z"
+        );
+        assert!(normalize_time("@x").is_err());
+    }
+
     #[test]
     fn test_parse_time_invalid() {
         assert!(parse_time("not-a-date").is_err());
@@ -1,17 +1,38 @@
 //! ScoutAPM API client library.
 //!
 //! Provides a typed client for the ScoutAPM REST API: apps, metrics, endpoints,
-//! traces, errors, and insights.
+//! traces, errors, and insights, plus metric series summaries and typed trace models.
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
 pub mod error;
+pub mod fixtures;
 pub mod helpers;
+pub mod metrics;
+pub mod models;
+pub mod paths;
 pub mod secret;
 
-pub use client::Client;
-pub use error::{ApiError, AuthError, Error};
-pub use helpers::{format_timestamp_display, get_api_key, parse_scout_url, ApiKeySource};
-pub use secret::{bitwarden, keepassxc, one_password};
+#[cfg(feature = "blocking")]
+pub use blocking::BlockingClient;
+pub use client::{
+    Client, Dashboard, DashboardMetric, EndpointComparison, EndpointTraces, InsightsHistoryPage,
+    RequestLog, RequestLogger, DASHBOARD_METRICS, INSIGHTS_LIMIT_MAX, TRACE_LIST_LIMIT,
+    VALID_INSIGHTS, VALID_METRICS,
+};
+pub use error::{ApiError, ApiErrorKind, AuthError, Error};
+pub use helpers::{
+    annotate_error_share, build_scout_url, encode_endpoint_id, endpoint_id_of, extract_list,
+    filter_by_time, filter_insights_by_severity, format_timestamp_as, format_timestamp_display,
+    get_api_key, humanize_timestamp, insight_severity, parse_scout_url, parse_severity,
+    sort_traces_slowest, trace_duration, ApiKeySource, TimeDisplay,
+};
+pub use metrics::{compare_summaries, series_points, MetricDelta, MetricSummary};
+pub use models::{Span, SpanStart, Trace};
+#[cfg(windows)]
+pub use secret::windows_credential;
+pub use secret::{age_file, bitwarden, keepassxc, libsecret, one_password, SecretString};
 
 /// Library version for User-Agent and diagnostics.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,184 @@
+//! Metric time-series helpers: point extraction and summary statistics.
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Summary statistics over the points of one metric series.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricSummary {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// Value of the newest point.
+    pub last: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub p99: f64,
+}
+
+impl MetricSummary {
+    /// Summarize `(timestamp, value)` points; `None` when there are no points.
+    /// `last` is taken from the point with the greatest timestamp.
+    pub fn from_points(points: &[(String, f64)]) -> Option<Self> {
+        let last = points.iter().max_by(|a, b| a.0.cmp(&b.0))?.1;
+        let mut values: Vec<f64> = points.iter().map(|(_, v)| *v).collect();
+        values.sort_by(|a, b| a.total_cmp(b));
+        let count = values.len();
+        Some(Self {
+            count,
+            min: values[0],
+            max: values[count - 1],
+            mean: values.iter().sum::<f64>() / count as f64,
+            last,
+            p50: percentile(&values, 50.0),
+            p95: percentile(&values, 95.0),
+            p99: percentile(&values, 99.0),
+        })
+    }
+}
+
+/// One statistic of a [`MetricSummary`] compared between two windows.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricDelta {
+    pub stat: &'static str,
+    pub current: f64,
+    pub previous: f64,
+    /// `current - previous`.
+    pub delta: f64,
+    /// `delta` as a percentage of `previous`; `None` when `previous` is zero.
+    pub delta_pct: Option<f64>,
+}
+
+/// Compare every statistic of `current` with `previous`, in [`MetricSummary`] field order.
+pub fn compare_summaries(current: &MetricSummary, previous: &MetricSummary) -> Vec<MetricDelta> {
+    let stats = |s: &MetricSummary| {
+        [
+            ("count", s.count as f64),
+            ("min", s.min),
+            ("max", s.max),
+            ("mean", s.mean),
+            ("last", s.last),
+            ("p50", s.p50),
+            ("p95", s.p95),
+            ("p99", s.p99),
+        ]
+    };
+    stats(current)
+        .into_iter()
+        .zip(stats(previous))
+        .map(|((stat, current), (_, previous))| {
+            let delta = current - previous;
+            MetricDelta {
+                stat,
+                current,
+                previous,
+                delta,
+                delta_pct: (previous != 0.0).then(|| delta / previous * 100.0),
+            }
+        })
+        .collect()
+}
+
+/// Linear-interpolated percentile of non-empty, ascending `sorted`.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = pct / 100.0 * (sorted.len() - 1) as f64;
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+/// `(timestamp, value)` from a `[ts, value]` pair or a `{timestamp|time, value}` object.
+fn point_of(p: &Value) -> Option<(String, f64)> {
+    let num = |v: &Value| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64));
+    if let Some(pair) = p
+        .get(0)
+        .and_then(|t| t.as_str())
+        .zip(p.get(1).and_then(num))
+    {
+        return Some((pair.0.to_string(), pair.1));
+    }
+    let ts = p
+        .get("timestamp")
+        .or_else(|| p.get("time"))
+        .and_then(|t| t.as_str())?;
+    Some((ts.to_string(), p.get("value").and_then(num)?))
+}
+
+/// Extract `(timestamp, value)` points from a metric response, in response order.
+///
+/// Accepts a bare array of points, an object with `points` or `data`, or an object nesting one of
+/// those (e.g. `{"response_time": {"points": [...]}}`; the first child with points wins).
+pub fn series_points(v: &Value) -> Vec<(String, f64)> {
+    if let Some(arr) = v.as_array() {
+        return arr.iter().filter_map(point_of).collect();
+    }
+    let Some(obj) = v.as_object() else {
+        return Vec::new();
+    };
+    if let Some(arr) = obj
+        .get("points")
+        .or_else(|| obj.get("data"))
+        .and_then(|a| a.as_array())
+    {
+        return arr.iter().filter_map(point_of).collect();
+    }
+    obj.values()
+        .map(series_points)
+        .find(|points| !points.is_empty())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn series_points_shapes() {
+        let pairs = json!([["2025-01-01T00:00:00Z", 1.5], ["2025-01-01T01:00:00Z", 2]]);
+        assert_eq!(series_points(&pairs).len(), 2);
+        let objects = json!({"points": [{"time": "2025-01-01T00:00:00Z", "value": 3}]});
+        assert_eq!(
+            series_points(&objects),
+            vec![("2025-01-01T00:00:00Z".to_string(), 3.0)]
+        );
+        let nested = json!({"series": {"response_time": {"data": [["t", 4.0]]}}});
+        assert_eq!(series_points(&nested), vec![("t".to_string(), 4.0)]);
+        assert!(series_points(&json!({"series": {}})).is_empty());
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let points: Vec<(String, f64)> = (1..=5)
+            .rev()
+            .map(|i| (format!("2025-01-0{i}"), i as f64 * 10.0))
+            .collect();
+        let s = MetricSummary::from_points(&points).unwrap();
+        assert_eq!(s.count, 5);
+        assert_eq!((s.min, s.max, s.mean), (10.0, 50.0, 30.0));
+        assert_eq!(s.last, 50.0);
+        assert_eq!(s.p50, 30.0);
+        assert!((s.p95 - 48.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn compare_summaries_deltas() {
+        let current = MetricSummary::from_points(&[("a".to_string(), 150.0)]).unwrap();
+        let previous = MetricSummary::from_points(&[("a".to_string(), 100.0)]).unwrap();
+        let deltas = compare_summaries(&current, &previous);
+        assert_eq!(deltas.len(), 8);
+        let mean = deltas.iter().find(|d| d.stat == "mean").unwrap();
+        assert_eq!(
+            (mean.current, mean.previous, mean.delta),
+            (150.0, 100.0, 50.0)
+        );
+        assert_eq!(mean.delta_pct, Some(50.0));
+        let zero = MetricSummary::from_points(&[("a".to_string(), 0.0)]).unwrap();
+        assert_eq!(compare_summaries(&current, &zero)[1].delta_pct, None);
+    }
+
+    #[test]
+    fn summary_of_no_points_is_none() {
+        assert!(MetricSummary::from_points(&[]).is_none());
+    }
+}
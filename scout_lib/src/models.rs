@@ -0,0 +1,284 @@
+//! Typed views of API payloads that are otherwise handled as `serde_json::Value`.
+//!
+//! Conversion is lenient: the API documents span objects as free-form, so unknown shapes become
+//! missing fields rather than errors.
+
+use serde::Deserialize;
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// A trace as returned by [`Client::fetch_trace`](crate::Client::fetch_trace).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(from = "Value")]
+pub struct Trace {
+    pub id: Option<u64>,
+    /// Start of the request (ISO 8601).
+    pub time: Option<String>,
+    /// Total request time in milliseconds.
+    pub total_call_time: Option<f64>,
+    /// Endpoint name, e.g. `Controller/UsersController#index`.
+    pub metric_name: Option<String>,
+    pub uri: Option<String>,
+    /// The agent stopped recording spans part-way through.
+    pub limited: bool,
+    /// Spans as listed in the payload: flat (linked by `parent_id`) and/or nested via `children`.
+    pub spans: Vec<Span>,
+}
+
+/// One span of a [`Trace`]. Field names vary between agents; the common spellings are accepted.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(from = "Value")]
+pub struct Span {
+    /// `id` (numbers are kept as their decimal text).
+    pub id: Option<String>,
+    /// `parent_id` of a flat span list.
+    pub parent_id: Option<String>,
+    /// `name`, `operation`, `metric_name` or `type`.
+    pub name: Option<String>,
+    /// `duration`, `total_call_time`, `duration_ms` or `total_time`, in milliseconds.
+    pub duration_ms: Option<f64>,
+    /// `start_time`, `start`, `started_at` or `time`.
+    pub start: Option<SpanStart>,
+    /// Spans nested under this one in the payload.
+    pub children: Vec<Span>,
+    /// The span object as received, for fields not modelled here.
+    pub raw: Value,
+}
+
+/// When a span started: an offset (number) or a timestamp (text). Offsets order before timestamps.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpanStart {
+    Offset(f64),
+    Timestamp(String),
+}
+
+impl SpanStart {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Offset(a), Self::Offset(b)) => a.total_cmp(b),
+            (Self::Timestamp(a), Self::Timestamp(b)) => a.cmp(b),
+            (Self::Offset(_), Self::Timestamp(_)) => Ordering::Less,
+            (Self::Timestamp(_), Self::Offset(_)) => Ordering::Greater,
+        }
+    }
+}
+
+fn first<'a>(v: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter().find_map(|k| v.get(*k).filter(|x| !x.is_null()))
+}
+
+fn id_text(v: Option<&Value>) -> Option<String> {
+    match v? {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+impl From<&Value> for Span {
+    fn from(v: &Value) -> Self {
+        Self {
+            id: id_text(v.get("id")),
+            parent_id: id_text(v.get("parent_id")),
+            name: ["name", "operation", "metric_name", "type"]
+                .iter()
+                .find_map(|k| v.get(*k).and_then(|n| n.as_str()))
+                .map(String::from),
+            duration_ms: ["duration", "total_call_time", "duration_ms", "total_time"]
+                .iter()
+                .find_map(|k| v.get(*k).and_then(|n| n.as_f64())),
+            start: match first(v, &["start_time", "start", "started_at", "time"]) {
+                Some(Value::Number(n)) => n.as_f64().map(SpanStart::Offset),
+                Some(Value::String(s)) => Some(SpanStart::Timestamp(s.clone())),
+                _ => None,
+            },
+            children: v
+                .get("children")
+                .and_then(|c| c.as_array())
+                .map(|c| c.iter().map(Span::from).collect())
+                .unwrap_or_default(),
+            raw: v.clone(),
+        }
+    }
+}
+
+impl From<Value> for Span {
+    fn from(v: Value) -> Self {
+        Self::from(&v)
+    }
+}
+
+impl From<&Value> for Trace {
+    fn from(v: &Value) -> Self {
+        let text = |k: &str| v.get(k).and_then(|s| s.as_str()).map(String::from);
+        Self {
+            id: v.get("id").and_then(|i| i.as_u64()),
+            time: text("time"),
+            total_call_time: first(v, &["total_call_time", "duration"]).and_then(|n| n.as_f64()),
+            metric_name: text("metric_name"),
+            uri: text("uri"),
+            limited: v.get("limited").and_then(|l| l.as_bool()).unwrap_or(false),
+            spans: v
+                .get("spans")
+                .and_then(|s| s.as_array())
+                .map(|s| s.iter().map(Span::from).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Value> for Trace {
+    fn from(v: Value) -> Self {
+        Self::from(&v)
+    }
+}
+
+/// Order spans by start; spans without one keep their relative order after the others.
+fn by_start(spans: &mut [&Span]) {
+    spans.sort_by(|a, b| match (&a.start, &b.start) {
+        (Some(x), Some(y)) => x.cmp(y),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+}
+
+impl Trace {
+    /// Total request time in milliseconds.
+    pub fn total_duration_ms(&self) -> Option<f64> {
+        self.total_call_time
+    }
+
+    /// Every span, nested ones included (depth-first, payload order).
+    pub fn spans(&self) -> impl Iterator<Item = &Span> {
+        let mut stack: Vec<&Span> = self.spans.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let span = stack.pop()?;
+            stack.extend(span.children.iter().rev());
+            Some(span)
+        })
+    }
+
+    /// Flat spans by id, for resolving `parent_id`.
+    fn by_id(&self) -> HashMap<&str, &Span> {
+        self.spans()
+            .filter_map(|s| Some((s.id.as_deref()?, s)))
+            .collect()
+    }
+
+    /// Top-level spans (no parent in this trace), ordered by start.
+    pub fn roots(&self) -> Vec<&Span> {
+        let ids = self.by_id();
+        let mut roots: Vec<&Span> = self
+            .spans
+            .iter()
+            .filter(|s| !s.parent_id.as_deref().is_some_and(|p| ids.contains_key(p)))
+            .collect();
+        by_start(&mut roots);
+        roots
+    }
+
+    /// Direct children of `span` (nested ones and those naming it as `parent_id`), ordered by start.
+    pub fn children<'a>(&'a self, span: &'a Span) -> Vec<&'a Span> {
+        let mut children: Vec<&Span> = span.children.iter().collect();
+        if let Some(id) = span.id.as_deref() {
+            children.extend(
+                self.spans()
+                    .filter(|s| s.parent_id.as_deref() == Some(id) && !std::ptr::eq(*s, span)),
+            );
+        }
+        by_start(&mut children);
+        children
+    }
+
+    /// The span `span` belongs to, if any.
+    pub fn parent(&self, span: &Span) -> Option<&Span> {
+        if let Some(parent) = span
+            .parent_id
+            .as_deref()
+            .and_then(|p| self.by_id().get(p).copied())
+        {
+            return Some(parent);
+        }
+        self.spans()
+            .find(|s| s.children.iter().any(|c| std::ptr::eq(c, span)))
+    }
+
+    /// The `n` spans with the longest duration, longest first (spans without a duration are skipped).
+    pub fn slowest_spans(&self, n: usize) -> Vec<&Span> {
+        let mut spans: Vec<&Span> = self.spans().filter(|s| s.duration_ms.is_some()).collect();
+        spans.sort_by(|a, b| {
+            b.duration_ms
+                .unwrap_or(0.0)
+                .total_cmp(&a.duration_ms.unwrap_or(0.0))
+        });
+        spans.truncate(n);
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn flat_trace() -> Trace {
+        serde_json::from_value(json!({
+            "id": 7,
+            "metric_name": "Controller/UsersController#index",
+            "total_call_time": 200.0,
+            "spans": [
+                {"id": 3, "parent_id": 1, "name": "View/index", "duration": 20.0, "start_time": 2},
+                {"id": 1, "name": "Controller", "duration": 200.0, "start_time": 0},
+                {"id": 2, "parent_id": 1, "name": "SQL/User/find", "duration": 50.0, "start_time": 1},
+            ],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn trace_from_payload() {
+        let trace = flat_trace();
+        assert_eq!(trace.id, Some(7));
+        assert_eq!(trace.total_duration_ms(), Some(200.0));
+        assert_eq!(trace.spans().count(), 3);
+        let root = trace.roots();
+        assert_eq!(root.len(), 1);
+        let names: Vec<_> = trace
+            .children(root[0])
+            .iter()
+            .map(|s| s.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, ["SQL/User/find", "View/index"]);
+        let view = trace
+            .spans()
+            .find(|s| s.id.as_deref() == Some("3"))
+            .unwrap();
+        assert_eq!(trace.parent(view).and_then(|p| p.id.as_deref()), Some("1"));
+    }
+
+    #[test]
+    fn nested_children_and_slowest() {
+        let trace = Trace::from(&json!({
+            "spans": [{"name": "a", "duration": 10.0, "children": [
+                {"operation": "b", "duration_ms": 4.0, "children": [{"type": "c", "duration": 6.0}]},
+            ]}],
+        }));
+        assert_eq!(trace.spans().count(), 3);
+        let b = &trace.spans[0].children[0];
+        assert_eq!(trace.parent(b).and_then(|p| p.name.as_deref()), Some("a"));
+        let slowest: Vec<_> = trace
+            .slowest_spans(2)
+            .iter()
+            .map(|s| s.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(slowest, ["a", "c"]);
+    }
+
+    #[test]
+    fn lenient_conversion() {
+        let trace = Trace::from(&json!({"id": "x", "spans": "nope"}));
+        assert_eq!(trace, Trace::default());
+    }
+}
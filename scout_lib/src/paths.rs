@@ -0,0 +1,348 @@
+//! API paths of the [`Client`](crate::Client) requests, relative to the API base and with their query
+//! strings (e.g. `/apps/1/endpoints?from=...&to=...`).
+//!
+//! The typed methods build their URLs here, so a path passed to
+//! [`Client::get_raw`](crate::Client::get_raw) fetches exactly what the typed call would: the same
+//! validation, and the same default time window.
+
+use crate::client::{validate_insights_limit, validate_time_range, TRACE_WINDOW_SECS};
+use crate::client::{VALID_INSIGHTS, VALID_METRICS};
+use crate::error::Error;
+use crate::helpers::{calculate_range, format_time, parse_time};
+use chrono::Utc;
+
+/// The time window of a request: `range` (e.g. `1day`, ending at `to` or now) when given, else `from`
+/// and `to` as passed. A window with both ends is validated.
+pub fn time_window(
+    from: Option<&str>,
+    to: Option<&str>,
+    range: Option<&str>,
+) -> Result<(Option<String>, Option<String>), Error> {
+    let (from, to) = match range {
+        Some(r) => {
+            let (f, t) = calculate_range(r, to).map_err(Error::Other)?;
+            (Some(f), Some(t))
+        }
+        None => (from.map(String::from), to.map(String::from)),
+    };
+    if let (Some(f), Some(t)) = (&from, &to) {
+        validate_time_range(f, t)?;
+    }
+    Ok((from, to))
+}
+
+/// [`time_window`] with both ends filled in: `to` defaults to now and `from` to 7 days before `to`.
+pub fn time_window_or_last_week(
+    from: Option<&str>,
+    to: Option<&str>,
+    range: Option<&str>,
+) -> Result<(String, String), Error> {
+    let (from, to) = match (range, from, to) {
+        (Some(r), _, to) => calculate_range(r, to).map_err(Error::Other)?,
+        (None, from, to) => {
+            let to = to
+                .map(String::from)
+                .unwrap_or_else(|| format_time(Utc::now()));
+            let from = match from {
+                Some(f) => f.to_string(),
+                None => calculate_range("7days", Some(&to)).map_err(Error::Other)?.0,
+            };
+            (from, to)
+        }
+    };
+    validate_time_range(&from, &to)?;
+    Ok((from, to))
+}
+
+/// `metric_type` when it is one of [`VALID_METRICS`].
+pub(crate) fn validate_metric_type(metric_type: &str) -> Result<(), Error> {
+    if VALID_METRICS.contains(&metric_type) {
+        return Ok(());
+    }
+    Err(Error::Other(format!(
+        "Invalid metric_type. Must be one of: {}",
+        VALID_METRICS.join(", ")
+    )))
+}
+
+/// `insight_type` when it is one of [`VALID_INSIGHTS`].
+fn validate_insight_type(insight_type: &str) -> Result<(), Error> {
+    if VALID_INSIGHTS.contains(&insight_type) {
+        return Ok(());
+    }
+    Err(Error::Other(format!(
+        "Invalid insight_type. Must be one of: {}",
+        VALID_INSIGHTS.join(", ")
+    )))
+}
+
+/// `path` followed by the query string of the parameters that are set, values URL-encoded.
+fn with_query(path: String, params: &[(&str, Option<String>)]) -> String {
+    let query: Vec<String> = params
+        .iter()
+        .filter_map(|(key, value)| {
+            let value = value.as_deref()?;
+            Some(format!("{}={}", key, urlencoding::encode(value)))
+        })
+        .collect();
+    if query.is_empty() {
+        path
+    } else {
+        format!("{}?{}", path, query.join("&"))
+    }
+}
+
+/// Path of [`Client::list_apps`](crate::Client::list_apps).
+pub fn apps_path() -> String {
+    "/apps".to_string()
+}
+
+/// Path of [`Client::get_app`](crate::Client::get_app).
+pub fn app_path(app_id: u64) -> String {
+    format!("/apps/{}", app_id)
+}
+
+/// Path of [`Client::list_metrics`](crate::Client::list_metrics).
+pub fn metrics_path(app_id: u64) -> String {
+    format!("/apps/{}/metrics", app_id)
+}
+
+/// Path of [`Client::get_metric`](crate::Client::get_metric); see [`time_window`].
+pub fn metric_path(
+    app_id: u64,
+    metric_type: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    range: Option<&str>,
+) -> Result<String, Error> {
+    validate_metric_type(metric_type)?;
+    let (from, to) = time_window(from, to, range)?;
+    Ok(with_query(
+        format!("/apps/{}/metrics/{}", app_id, metric_type),
+        &[("from", from), ("to", to)],
+    ))
+}
+
+/// Path of [`Client::list_endpoints`](crate::Client::list_endpoints); see
+/// [`time_window_or_last_week`].
+pub fn endpoints_path(
+    app_id: u64,
+    from: Option<&str>,
+    to: Option<&str>,
+    range: Option<&str>,
+) -> Result<String, Error> {
+    let (from, to) = time_window_or_last_week(from, to, range)?;
+    Ok(with_query(
+        format!("/apps/{}/endpoints", app_id),
+        &[("from", Some(from)), ("to", Some(to))],
+    ))
+}
+
+/// Path of [`Client::get_endpoint_metrics`](crate::Client::get_endpoint_metrics); see
+/// [`time_window`].
+pub fn endpoint_metric_path(
+    app_id: u64,
+    endpoint_id: &str,
+    metric_type: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    range: Option<&str>,
+) -> Result<String, Error> {
+    validate_metric_type(metric_type)?;
+    let (from, to) = time_window(from, to, range)?;
+    Ok(with_query(
+        format!(
+            "/apps/{}/endpoints/{}/metrics/{}",
+            app_id, endpoint_id, metric_type
+        ),
+        &[("from", from), ("to", to)],
+    ))
+}
+
+/// Path of [`Client::get_endpoint_histogram`](crate::Client::get_endpoint_histogram); see
+/// [`time_window`].
+pub fn endpoint_histogram_path(
+    app_id: u64,
+    endpoint_id: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    range: Option<&str>,
+) -> Result<String, Error> {
+    let (from, to) = time_window(from, to, range)?;
+    Ok(with_query(
+        format!("/apps/{}/endpoints/{}/histogram", app_id, endpoint_id),
+        &[("from", from), ("to", to)],
+    ))
+}
+
+/// Path of one endpoint trace listing request; see [`time_window_or_last_week`]. One request covers
+/// at most 7 days, so a wider window is an error here
+/// ([`Client::list_endpoint_traces`](crate::Client::list_endpoint_traces) splits it instead).
+pub fn endpoint_traces_path(
+    app_id: u64,
+    endpoint_id: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    range: Option<&str>,
+) -> Result<String, Error> {
+    let (from, to) = time_window_or_last_week(from, to, range)?;
+    let span = parse_time(&to).map_err(Error::Other)? - parse_time(&from).map_err(Error::Other)?;
+    if span.num_seconds() > TRACE_WINDOW_SECS {
+        return Err(Error::Other(
+            "One trace listing request cannot cover more than 7 days".to_string(),
+        ));
+    }
+    Ok(with_query(
+        format!("/apps/{}/endpoints/{}/traces", app_id, endpoint_id),
+        &[("from", Some(from)), ("to", Some(to))],
+    ))
+}
+
+/// Path of [`Client::fetch_trace`](crate::Client::fetch_trace).
+pub fn trace_path(app_id: u64, trace_id: u64) -> String {
+    format!("/apps/{}/traces/{}", app_id, trace_id)
+}
+
+/// Path of [`Client::list_error_groups`](crate::Client::list_error_groups); a window with both ends is
+/// validated.
+pub fn error_groups_path(
+    app_id: u64,
+    from: Option<&str>,
+    to: Option<&str>,
+    endpoint: Option<&str>,
+) -> Result<String, Error> {
+    let (from, to) = time_window(from, to, None)?;
+    Ok(with_query(
+        format!("/apps/{}/error_groups", app_id),
+        &[
+            ("from", from),
+            ("to", to),
+            ("endpoint", endpoint.map(String::from)),
+        ],
+    ))
+}
+
+/// Path of [`Client::get_error_group`](crate::Client::get_error_group).
+pub fn error_group_path(app_id: u64, error_id: u64) -> String {
+    format!("/apps/{}/error_groups/{}", app_id, error_id)
+}
+
+/// Path of [`Client::get_error_group_errors`](crate::Client::get_error_group_errors).
+pub fn error_group_errors_path(app_id: u64, error_id: u64) -> String {
+    format!("/apps/{}/error_groups/{}/errors", app_id, error_id)
+}
+
+/// Path of [`Client::get_all_insights`](crate::Client::get_all_insights).
+pub fn insights_path(app_id: u64, limit: Option<u32>) -> Result<String, Error> {
+    validate_insights_limit(limit)?;
+    Ok(with_query(
+        format!("/apps/{}/insights", app_id),
+        &[("limit", limit.map(|l| l.to_string()))],
+    ))
+}
+
+/// Path of [`Client::get_insight_by_type`](crate::Client::get_insight_by_type).
+pub fn insight_path(app_id: u64, insight_type: &str, limit: Option<u32>) -> Result<String, Error> {
+    validate_insight_type(insight_type)?;
+    validate_insights_limit(limit)?;
+    Ok(with_query(
+        format!("/apps/{}/insights/{}", app_id, insight_type),
+        &[("limit", limit.map(|l| l.to_string()))],
+    ))
+}
+
+/// Path of [`Client::get_insights_history`](crate::Client::get_insights_history), or of
+/// [`Client::get_insights_history_by_type`](crate::Client::get_insights_history_by_type) when
+/// `insight_type` is given.
+#[allow(clippy::too_many_arguments)]
+pub fn insights_history_path(
+    app_id: u64,
+    insight_type: Option<&str>,
+    from: Option<&str>,
+    to: Option<&str>,
+    limit: Option<u32>,
+    pagination_cursor: Option<u64>,
+    pagination_direction: Option<&str>,
+    pagination_page: Option<u32>,
+) -> Result<String, Error> {
+    let path = match insight_type {
+        Some(t) => {
+            validate_insight_type(t)?;
+            format!("/apps/{}/insights/history/{}", app_id, t)
+        }
+        None => format!("/apps/{}/insights/history", app_id),
+    };
+    Ok(with_query(
+        path,
+        &[
+            ("from", from.map(String::from)),
+            ("to", to.map(String::from)),
+            ("limit", limit.map(|l| l.to_string())),
+            (
+                "pagination_cursor",
+                pagination_cursor.map(|c| c.to_string()),
+            ),
+            (
+                "pagination_direction",
+                pagination_direction.map(String::from),
+            ),
+            ("pagination_page", pagination_page.map(|p| p.to_string())),
+        ],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_carry_only_the_parameters_that_are_set() {
+        assert_eq!(metrics_path(1), "/apps/1/metrics");
+        assert_eq!(
+            metric_path(1, "apdex", None, None, None).unwrap(),
+            "/apps/1/metrics/apdex"
+        );
+        assert_eq!(
+            error_groups_path(1, None, None, Some("Users#show")).unwrap(),
+            "/apps/1/error_groups?endpoint=Users%23show"
+        );
+        assert_eq!(
+            insights_history_path(
+                1,
+                Some("n_plus_one"),
+                None,
+                None,
+                Some(5),
+                Some(9),
+                None,
+                None
+            )
+            .unwrap(),
+            "/apps/1/insights/history/n_plus_one?limit=5&pagination_cursor=9"
+        );
+        assert!(metric_path(1, "bogus", None, None, None).is_err());
+        assert!(insight_path(1, "bogus", None).is_err());
+        assert!(insights_path(1, Some(0)).is_err());
+    }
+
+    #[test]
+    fn default_windows_fill_in_the_missing_end() {
+        let from = format_time(Utc::now() - chrono::Duration::days(1));
+        let path = endpoints_path(1, Some(&from), None, None).unwrap();
+        let prefix = format!("/apps/1/endpoints?from={}&to=", urlencoding::encode(&from));
+        assert!(path.starts_with(&prefix), "{path}");
+        let path = endpoints_path(1, None, Some("2025-01-08T00:00:00Z"), None).unwrap();
+        assert_eq!(
+            path,
+            "/apps/1/endpoints?from=2025-01-01T00%3A00%3A00Z&to=2025-01-08T00%3A00%3A00Z"
+        );
+        assert!(endpoint_traces_path(
+            1,
+            "e",
+            Some("2025-01-01T00:00:00Z"),
+            Some("2025-01-12T00:00:00Z"),
+            None
+        )
+        .is_err());
+    }
+}
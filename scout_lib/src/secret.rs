@@ -1,11 +1,72 @@
 //! Secret backends for reading the Scout APM API key.
 //!
-//! Resolution is via secret backends only (1Password, Bitwarden, KeePassXC).
-//! Plain-text API keys (e.g. env vars or explicit keys) are intentionally not supported;
-//! see README and CLI help for the recommended secret-backend setup.
+//! Resolution is via secret backends (1Password, Bitwarden, KeePassXC, libsecret, age, and
+//! Windows Credential Manager on Windows).
+//! Plain-text API keys are not supported by default; the only exception is [`env_var`], which must be
+//! opted into explicitly (for CI containers that cannot run a vault CLI).
+//! See README and CLI help for the recommended secret-backend setup.
 
+use std::fmt;
 use std::process::Command;
 
+/// Header names whose values carry credentials; matched case-insensitively.
+const SENSITIVE_HEADERS: [&str; 2] = ["x-scout-api", "authorization"];
+
+/// A secret value (the API key) whose `Debug` and `Display` print `***`, so it cannot leak into logs,
+/// error messages, or `{:?}` dumps. Use [`SecretString::expose`] only where the raw value is needed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The raw secret; only for sending it where it belongs (e.g. the `X-SCOUT-API` header).
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+/// Replace the value of every object field named like a credential header (`X-SCOUT-API`,
+/// `Authorization`; any case, at any depth) with `"***"`.
+pub(crate) fn redact_headers(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                if SENSITIVE_HEADERS
+                    .iter()
+                    .any(|h| key.eq_ignore_ascii_case(h))
+                {
+                    *v = serde_json::Value::String("***".to_string());
+                } else {
+                    redact_headers(v);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_headers),
+        _ => {}
+    }
+}
+
 /// Read secret from a subprocess; stderr is discarded to avoid leaking into output.
 fn run_cmd(args: &[&str]) -> Option<String> {
     run_cmd_with_env(args, &[])
@@ -36,6 +97,7 @@ fn run_cmd_with_env(args: &[&str], env_extra: &[(&str, &str)]) -> Option<String>
 /// Configure via:
 /// - `SCOUT_OP_ENTRY_PATH`: `op://Vault/Item` (field name from `SCOUT_OP_FIELD`, default `API_KEY`)
 /// - Or `SCOUT_OP_VAULT` + `SCOUT_OP_ITEM` + optional `SCOUT_OP_FIELD` (default `API_KEY`)
+/// - `SCOUT_OP_ACCOUNT`: optional account (sign-in address or id) when several accounts are signed in
 pub fn one_password() -> Option<String> {
     let field = std::env::var("SCOUT_OP_FIELD").unwrap_or_else(|_| "API_KEY".to_string());
     let field = field.trim();
@@ -51,7 +113,7 @@ pub fn one_password() -> Option<String> {
         // op://Vault/Item -> op read "op://Vault/Item/Field"
         let base = path.trim_end_matches('/');
         let uri = format!("{}/{}", base, field);
-        return run_cmd(&["op", "read", &uri]).filter(|s| !s.is_empty());
+        return op_read(&uri);
     }
 
     let vault = std::env::var("SCOUT_OP_VAULT")
@@ -63,19 +125,43 @@ pub fn one_password() -> Option<String> {
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty())?;
     let uri = format!("op://{}/{}/{}", vault, item, field);
-    run_cmd(&["op", "read", &uri]).filter(|s| !s.is_empty())
+    op_read(&uri)
 }
 
-/// Bitwarden CLI (`bw get password`).
+/// `op read <uri>`, scoped to `SCOUT_OP_ACCOUNT` when set.
+fn op_read(uri: &str) -> Option<String> {
+    let account = std::env::var("SCOUT_OP_ACCOUNT").ok();
+    let args = op_read_args(uri, account.as_deref());
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_cmd(&args).filter(|s| !s.is_empty())
+}
+
+/// Command line of [`op_read`]; a blank `account` is ignored.
+fn op_read_args(uri: &str, account: Option<&str>) -> Vec<String> {
+    let mut args = vec!["op".to_string(), "read".to_string(), uri.to_string()];
+    if let Some(account) = account.map(str::trim).filter(|a| !a.is_empty()) {
+        args.extend(["--account".to_string(), account.to_string()]);
+    }
+    args
+}
+
+/// Bitwarden CLI (`bw get password`, or `bw get item` when looked up by name).
 ///
 /// Configure via:
 /// - `SCOUT_BW_ITEM_ID`: UUID of the login item (from `bw list items`)
+/// - Or `SCOUT_BW_ITEM_NAME`: name of the login item (must match a single item)
+/// - `SCOUT_BW_FIELD`: optional custom field holding the key (e.g. `scout_api_key`) instead of the password
 /// - `SCOUT_BW_SESSION`: optional session key (from `bw unlock --raw`) if vault is locked
 pub fn bitwarden() -> Option<String> {
-    let id = std::env::var("SCOUT_BW_ITEM_ID")
-        .ok()
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())?;
+    let var = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+    let id = var("SCOUT_BW_ITEM_ID");
+    let name = var("SCOUT_BW_ITEM_NAME");
+    let field = var("SCOUT_BW_FIELD");
     let env_extra: Vec<(String, String)> = std::env::var("SCOUT_BW_SESSION")
         .ok()
         .filter(|s| !s.trim().is_empty())
@@ -86,7 +172,35 @@ pub fn bitwarden() -> Option<String> {
         .iter()
         .map(|(k, v)| (k.as_str(), v.as_str()))
         .collect();
-    run_cmd_with_env(&["bw", "get", "password", &id], &env_refs).filter(|s| !s.is_empty())
+    match (id, name, field) {
+        (Some(id), _, None) => {
+            run_cmd_with_env(&["bw", "get", "password", &id], &env_refs).filter(|s| !s.is_empty())
+        }
+        (Some(item), _, field) | (None, Some(item), field) => {
+            run_cmd_with_env(&["bw", "get", "item", &item], &env_refs)
+                .and_then(|j| bw_item_value(&j, field.as_deref()))
+        }
+        (None, None, _) => None,
+    }
+}
+
+/// The key in a `bw get item` JSON document: the custom field named `field` (from `fields[]`) if given,
+/// else `login.password`. `None` when it is missing or empty.
+fn bw_item_value(json: &str, field: Option<&str>) -> Option<String> {
+    let item: serde_json::Value = serde_json::from_str(json).ok()?;
+    let value = match field {
+        Some(field) => item
+            .get("fields")?
+            .as_array()?
+            .iter()
+            .find(|f| f.get("name").and_then(|n| n.as_str()) == Some(field))?
+            .get("value")?,
+        None => item.get("login")?.get("password")?,
+    };
+    value
+        .as_str()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
 }
 
 /// KeePassXC CLI (`keepassxc-cli show`).
@@ -112,10 +226,155 @@ pub fn keepassxc() -> Option<String> {
     run_cmd(&["keepassxc-cli", "show", "-a", attr, &db, &entry]).filter(|s| !s.is_empty())
 }
 
+/// GNOME keyring / libsecret via `secret-tool lookup`.
+///
+/// Configure via:
+/// - `SCOUT_SECRET_TOOL_ATTRS`: whitespace-separated attribute/value pairs identifying the secret
+///   (e.g. `service scout`, as stored with `secret-tool store --label=Scout service scout`)
+pub fn libsecret() -> Option<String> {
+    let attrs = std::env::var("SCOUT_SECRET_TOOL_ATTRS").ok()?;
+    let attrs = secret_tool_attrs(&attrs)?;
+    let mut args = vec!["secret-tool", "lookup"];
+    args.extend(attrs);
+    run_cmd(&args).filter(|s| !s.is_empty())
+}
+
+/// age-encrypted file (`age -d -i <identity> <file>`).
+///
+/// The plaintext is read from the subprocess's stdout pipe; it is never written to disk.
+///
+/// Configure via:
+/// - `SCOUT_AGE_FILE`: path to the encrypted file holding the key
+/// - `SCOUT_AGE_IDENTITY`: path to the identity (private key) file
+pub fn age_file() -> Option<String> {
+    let file = std::env::var("SCOUT_AGE_FILE")
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())?;
+    let identity = std::env::var("SCOUT_AGE_IDENTITY")
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())?;
+    run_cmd(&["age", "-d", "-i", &identity, &file]).filter(|s| !s.is_empty())
+}
+
+/// PowerShell script printing the password stored in the Credential Manager for the resource named
+/// by `$env:SCOUT_WINCRED_TARGET` as base64 (via the WinRT `PasswordVault`); exits non-zero when there
+/// is no such credential.
+#[cfg(windows)]
+const WINCRED_SCRIPT: &str = r#"$ErrorActionPreference = 'Stop'
+$null = [Windows.Security.Credentials.PasswordVault, Windows.Security.Credentials, ContentType = WindowsRuntime]
+$c = (New-Object Windows.Security.Credentials.PasswordVault).FindAllByResource($env:SCOUT_WINCRED_TARGET) | Select-Object -First 1
+$c.RetrievePassword()
+[Console]::Out.Write([Convert]::ToBase64String([Text.Encoding]::UTF8.GetBytes($c.Password)))"#;
+
+/// Windows Credential Manager (web credential, read with the `PasswordVault` API through PowerShell).
+///
+/// The secret travels base64-encoded over the subprocess's stdout pipe only; the resource name is passed
+/// in the environment, never on the command line.
+///
+/// Configure via:
+/// - `SCOUT_WINCRED_TARGET`: resource name of the credential (e.g. `scout`, as stored with
+///   `(New-Object Windows.Security.Credentials.PasswordVault).Add((New-Object
+///   Windows.Security.Credentials.PasswordCredential('scout', 'scout', (Read-Host))))`)
+#[cfg(windows)]
+pub fn windows_credential() -> Option<String> {
+    use base64::Engine;
+    let target = std::env::var("SCOUT_WINCRED_TARGET")
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())?;
+    let b64 = base64::engine::general_purpose::STANDARD;
+    // -EncodedCommand (base64 of UTF-16LE) sidesteps quoting the script on the command line.
+    let script: Vec<u8> = WINCRED_SCRIPT
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    let encoded = run_cmd_with_env(
+        &[
+            "powershell",
+            "-NoProfile",
+            "-NonInteractive",
+            "-EncodedCommand",
+            &b64.encode(script),
+        ],
+        &[("SCOUT_WINCRED_TARGET", &target)],
+    )?;
+    decode_password(&encoded)
+}
+
+/// Plain environment variable, **insecure**: the key is visible to every process that can read this
+/// one's environment. Last resort for CI containers where a mounted secret env var is the only option.
+///
+/// Configure via:
+/// - `SCOUT_API_KEY`: the key itself
+/// - `SCOUT_ALLOW_ENV_KEY=1`: required opt-in; without it `SCOUT_API_KEY` is ignored
+pub fn env_var() -> Option<String> {
+    env_key(
+        std::env::var("SCOUT_ALLOW_ENV_KEY").ok().as_deref(),
+        std::env::var("SCOUT_API_KEY").ok(),
+    )
+}
+
+/// Decode the base64 UTF-8 password printed by `WINCRED_SCRIPT` (trimmed, non-empty).
+#[cfg(any(windows, test))]
+fn decode_password(encoded: &str) -> Option<String> {
+    use base64::Engine;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim().as_bytes())
+        .ok()?;
+    let password = String::from_utf8(bytes).ok()?;
+    Some(password.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// `key` (trimmed, non-empty) only when `allow` is exactly `1`.
+fn env_key(allow: Option<&str>, key: Option<String>) -> Option<String> {
+    if allow.map(str::trim) != Some("1") {
+        return None;
+    }
+    key.map(|k| k.trim().to_string()).filter(|k| !k.is_empty())
+}
+
+/// Split `SCOUT_SECRET_TOOL_ATTRS` into attribute/value words; `None` unless it is one or more pairs.
+fn secret_tool_attrs(attrs: &str) -> Option<Vec<&str>> {
+    let words: Vec<&str> = attrs.split_whitespace().collect();
+    (!words.is_empty() && words.len().is_multiple_of(2)).then_some(words)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn op_read_args_with_account() {
+        assert_eq!(
+            op_read_args("op://V/I/F", None),
+            ["op", "read", "op://V/I/F"]
+        );
+        assert_eq!(
+            op_read_args("op://V/I/F", Some("  ")),
+            ["op", "read", "op://V/I/F"]
+        );
+        assert_eq!(
+            op_read_args("op://V/I/F", Some("team.1password.com")),
+            [
+                "op",
+                "read",
+                "op://V/I/F",
+                "--account",
+                "team.1password.com"
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_password_base64_utf8() {
+        assert_eq!(decode_password("a2V5LTEyMwo=").as_deref(), Some("key-123"));
+        assert_eq!(decode_password(""), None);
+        assert_eq!(decode_password("not base64!"), None);
+        assert_eq!(decode_password("/w=="), None);
+    }
+
     #[test]
     fn one_password_not_configured() {
         // No env set -> None
@@ -127,8 +386,85 @@ mod tests {
         assert!(bitwarden().is_none());
     }
 
+    #[test]
+    fn bw_item_value_password_from_item_json() {
+        let item = r#"{"id":"u-1","name":"Scout","login":{"username":"me","password":"key-123"}}"#;
+        assert_eq!(bw_item_value(item, None).as_deref(), Some("key-123"));
+        assert_eq!(bw_item_value(r#"{"login":{"password":null}}"#, None), None);
+        assert_eq!(bw_item_value(r#"{"type":2,"notes":"x"}"#, None), None);
+        assert_eq!(bw_item_value("not json", None), None);
+    }
+
+    #[test]
+    fn bw_item_value_custom_field() {
+        let item = r#"{"login":{"password":"pw"},"fields":[
+            {"name":"other","value":"x","type":0},
+            {"name":"scout_api_key","value":"key-456","type":1}]}"#;
+        assert_eq!(
+            bw_item_value(item, Some("scout_api_key")).as_deref(),
+            Some("key-456")
+        );
+        assert_eq!(bw_item_value(item, Some("missing")), None);
+        assert_eq!(
+            bw_item_value(r#"{"login":{}}"#, Some("scout_api_key")),
+            None
+        );
+    }
+
     #[test]
     fn keepassxc_not_configured() {
         assert!(keepassxc().is_none());
     }
+
+    #[test]
+    fn libsecret_not_configured() {
+        assert!(libsecret().is_none());
+    }
+
+    #[test]
+    fn age_file_not_configured() {
+        assert!(age_file().is_none());
+    }
+
+    #[test]
+    fn env_key_requires_explicit_opt_in() {
+        let key = || Some(" key-123\n".to_string());
+        assert_eq!(env_key(Some("1"), key()).as_deref(), Some("key-123"));
+        assert_eq!(env_key(None, key()), None);
+        assert_eq!(env_key(Some("true"), key()), None);
+        assert_eq!(env_key(Some("0"), key()), None);
+        assert_eq!(env_key(Some("1"), Some("  ".to_string())), None);
+        assert_eq!(env_key(Some("1"), None), None);
+    }
+
+    #[test]
+    fn secret_tool_attrs_need_pairs() {
+        assert_eq!(
+            secret_tool_attrs(" service  scout "),
+            Some(vec!["service", "scout"])
+        );
+        assert_eq!(secret_tool_attrs("service"), None);
+        assert_eq!(secret_tool_attrs("  "), None);
+    }
+
+    #[test]
+    fn secret_string_is_redacted() {
+        let s = SecretString::new("sk-live-123");
+        assert_eq!(format!("{s}"), "***");
+        assert_eq!(format!("{s:?}"), "***");
+        assert_eq!(s.expose(), "sk-live-123");
+    }
+
+    #[test]
+    fn redact_headers_scrubs_nested_fields() {
+        let mut v = serde_json::json!({
+            "headers": {"X-SCOUT-API": "sk-live-123", "Accept": "application/json"},
+            "requests": [{"authorization": "Bearer sk-live-123"}],
+        });
+        redact_headers(&mut v);
+        assert_eq!(v["headers"]["X-SCOUT-API"], "***");
+        assert_eq!(v["headers"]["Accept"], "application/json");
+        assert_eq!(v["requests"][0]["authorization"], "***");
+        assert!(!v.to_string().contains("sk-live-123"));
+    }
 }